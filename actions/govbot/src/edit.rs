@@ -0,0 +1,312 @@
+use anyhow::{bail, Context, Result};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+/// A format-preserving in-memory model of a govbot.yml document.
+///
+/// Unlike `publish::load_config` (which parses into a `GovbotConfig` for
+/// reading), `GovbotDocument` keeps the original lines around so targeted
+/// edits — adding a repo, inserting a tag, changing the base URL — leave
+/// every untouched comment, blank line, and key order exactly as the user
+/// left them. This is the same relationship `toml_edit` has to `toml`: one
+/// model for typed reads, a separate one for surgical in-place writes.
+pub struct GovbotDocument {
+    lines: Vec<String>,
+    /// Whether `contents` ended in `\n`, so [`Display`] doesn't add one that
+    /// wasn't there (or drop one that was) for files it didn't otherwise
+    /// touch.
+    trailing_newline: bool,
+}
+
+impl GovbotDocument {
+    /// Load a govbot.yml document, preserving every line verbatim.
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read config file: {}", path.display()))?;
+        Ok(Self::parse(&contents))
+    }
+
+    /// Parse a govbot.yml document from its raw text.
+    pub fn parse(contents: &str) -> Self {
+        GovbotDocument {
+            lines: contents.lines().map(|l| l.to_string()).collect(),
+            trailing_newline: contents.ends_with('\n'),
+        }
+    }
+
+    /// Write the document back to `path`.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, self.to_string())
+            .with_context(|| format!("Failed to write config file: {}", path.display()))?;
+        Ok(())
+    }
+
+    /// Append a repo entry to the `repos:` list.
+    pub fn add_repo(&mut self, repo: &str) -> Result<()> {
+        let section = self.find_top_level_section("repos:")?;
+        let entry = format!("  - {}", repo);
+        self.lines.insert(section.end, entry);
+        Ok(())
+    }
+
+    /// Remove a repo entry from the `repos:` list, if present.
+    pub fn remove_repo(&mut self, repo: &str) -> Result<()> {
+        let section = self.find_top_level_section("repos:")?;
+        let needle = format!("- {}", repo);
+        let mut i = section.start;
+        let mut end = section.end;
+        while i < end {
+            if self.lines[i].trim() == needle {
+                self.lines.remove(i);
+                end -= 1;
+            } else {
+                i += 1;
+            }
+        }
+        Ok(())
+    }
+
+    /// Insert a new tag definition (description + examples) into the
+    /// `tags:` block.
+    pub fn add_tag(&mut self, name: &str, description: &str, examples: &[String]) -> Result<()> {
+        let section = self.find_top_level_section("tags:")?;
+
+        let mut block = Vec::new();
+        block.push(format!("  {}:", name));
+        block.push("    description: |".to_string());
+        for line in description.lines() {
+            block.push(format!("      {}", line));
+        }
+        block.push("    examples:".to_string());
+        for example in examples {
+            block.push(format!("      - \"{}\"", escape_yaml_double_quoted(example)));
+        }
+
+        // Drop the "empty tags" placeholder comment block / `{}` marker if
+        // this is the first real tag being added.
+        let body: Vec<&String> = self.lines[section.start..section.end].iter().collect();
+        if body.iter().all(|line| {
+            let trimmed = line.trim();
+            trimmed.is_empty() || trimmed.starts_with('#') || trimmed == "{}"
+        }) {
+            self.lines.drain(section.start..section.end);
+            self.lines.splice(section.start..section.start, block);
+        } else {
+            self.lines.splice(section.end..section.end, block);
+        }
+
+        Ok(())
+    }
+
+    /// Change `build.base_url` in place.
+    pub fn set_base_url(&mut self, url: &str) -> Result<()> {
+        let section = self.find_top_level_section("build:")?;
+        for line in &mut self.lines[section.start..section.end] {
+            if let Some(indent_len) = line.find("base_url:") {
+                let indent = &line[..indent_len];
+                *line = format!("{}base_url: \"{}\"", indent, escape_yaml_double_quoted(url));
+                return Ok(());
+            }
+        }
+        bail!("build.base_url not found");
+    }
+
+    /// Find the line range of a top-level section's body: the lines after
+    /// `key:` up to (but not including) the next line that starts at column
+    /// 0, or end of document.
+    fn find_top_level_section(&self, key: &str) -> Result<Section> {
+        let header = self
+            .lines
+            .iter()
+            .position(|line| line.trim_end() == key)
+            .with_context(|| format!("'{}' section not found in govbot.yml", key))?;
+
+        let start = header + 1;
+        let mut end = start;
+        while end < self.lines.len() {
+            let line = &self.lines[end];
+            if !line.is_empty() && !line.starts_with(' ') && !line.starts_with('\t') {
+                break;
+            }
+            end += 1;
+        }
+
+        // Don't count the blank separator line that conventionally precedes
+        // the next section as part of this one, so inserts land right after
+        // the last real entry instead of after the blank line.
+        while end > start && self.lines[end - 1].trim().is_empty() {
+            end -= 1;
+        }
+
+        Ok(Section { start, end })
+    }
+}
+
+impl fmt::Display for GovbotDocument {
+    /// Render the document back to text, byte-for-byte identical to the
+    /// input except for the edits applied.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.lines.join("\n"))?;
+        if self.trailing_newline {
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+struct Section {
+    start: usize,
+    end: usize,
+}
+
+/// Escape `\` and `"` so `s` can be safely interpolated into a
+/// double-quoted YAML scalar, e.g. `format!("\"{}\"", escape_yaml_double_quoted(s))`.
+fn escape_yaml_double_quoted(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"# Govbot Configuration
+repos:
+  - il
+  - ca
+
+tags:
+  # Add your tags here.
+  {}
+
+build:
+  base_url: "https://example.com"
+  output_dir: "docs"
+  output_file: "feed.xml"
+"#;
+
+    #[test]
+    fn add_repo_preserves_unrelated_lines() {
+        let mut doc = GovbotDocument::parse(SAMPLE);
+        doc.add_repo("ny").unwrap();
+        let out = doc.to_string();
+
+        assert!(out.contains("  - il\n  - ca\n  - ny\n"));
+        assert!(out.contains("base_url: \"https://example.com\""));
+        assert!(out.contains("# Add your tags here."));
+    }
+
+    #[test]
+    fn remove_repo_drops_only_the_matching_entry() {
+        let mut doc = GovbotDocument::parse(SAMPLE);
+        doc.remove_repo("il").unwrap();
+        let out = doc.to_string();
+
+        assert!(!out.contains("- il"));
+        assert!(out.contains("- ca"));
+    }
+
+    #[test]
+    fn add_tag_replaces_empty_placeholder_and_keeps_build_block_intact() {
+        let mut doc = GovbotDocument::parse(SAMPLE);
+        doc.add_tag(
+            "education",
+            "Legislation about schools.",
+            &["Increases school funding".to_string()],
+        )
+        .unwrap();
+        let out = doc.to_string();
+
+        assert!(out.contains("  education:"));
+        assert!(out.contains("      Legislation about schools."));
+        assert!(out.contains("      - \"Increases school funding\""));
+        assert!(out.contains("base_url: \"https://example.com\""));
+        assert!(out.contains("output_file: \"feed.xml\""));
+    }
+
+    #[test]
+    fn add_tag_escapes_embedded_quotes_and_backslashes_so_the_result_still_parses() {
+        let mut doc = GovbotDocument::parse(SAMPLE);
+        doc.add_tag(
+            "weird",
+            "desc",
+            &[r#"Bill says "hello" to you"#.to_string(), r"a \ backslash".to_string()],
+        )
+        .unwrap();
+        let out = doc.to_string();
+
+        assert!(out.contains(r#"      - "Bill says \"hello\" to you""#));
+        assert!(out.contains(r#"      - "a \\ backslash""#));
+
+        let parsed: serde_yaml::Value =
+            serde_yaml::from_str(&out).expect("add_tag output with quoted examples should still parse as YAML");
+        let examples = parsed["tags"]["weird"]["examples"]
+            .as_sequence()
+            .expect("examples should be a sequence");
+        assert_eq!(examples[0].as_str().unwrap(), r#"Bill says "hello" to you"#);
+        assert_eq!(examples[1].as_str().unwrap(), r"a \ backslash");
+    }
+
+    #[test]
+    fn set_base_url_escapes_embedded_quotes_so_the_result_still_parses() {
+        let mut doc = GovbotDocument::parse(SAMPLE);
+        doc.set_base_url(r#"https://example.com/"weird"-path"#).unwrap();
+        let out = doc.to_string();
+
+        let parsed: serde_yaml::Value =
+            serde_yaml::from_str(&out).expect("set_base_url output with a quoted value should still parse as YAML");
+        assert_eq!(
+            parsed["build"]["base_url"].as_str().unwrap(),
+            r#"https://example.com/"weird"-path"#
+        );
+    }
+
+    #[test]
+    fn set_base_url_changes_only_that_field() {
+        let mut doc = GovbotDocument::parse(SAMPLE);
+        doc.set_base_url("https://new.example.com").unwrap();
+        let out = doc.to_string();
+
+        assert!(out.contains("base_url: \"https://new.example.com\""));
+        assert!(out.contains("output_dir: \"docs\""));
+        assert!(out.contains("- il"));
+    }
+
+    #[test]
+    fn edits_are_round_trip_safe_for_untouched_sections() {
+        let mut doc = GovbotDocument::parse(SAMPLE);
+        doc.set_base_url("https://new.example.com").unwrap();
+        let out = doc.to_string();
+
+        let unrelated_lines = [
+            "# Govbot Configuration",
+            "  - il",
+            "  - ca",
+            "tags:",
+            "  # Add your tags here.",
+            "  {}",
+            "build:",
+            "  output_dir: \"docs\"",
+            "  output_file: \"feed.xml\"",
+        ];
+        for line in unrelated_lines {
+            assert!(
+                out.lines().any(|actual| actual == line),
+                "expected untouched line preserved byte-for-byte: {}",
+                line
+            );
+        }
+    }
+
+    #[test]
+    fn round_trip_preserves_absence_of_a_trailing_newline() {
+        let doc = GovbotDocument::parse(SAMPLE.trim_end_matches('\n'));
+        assert_eq!(doc.to_string(), SAMPLE.trim_end_matches('\n'));
+    }
+
+    #[test]
+    fn round_trip_preserves_presence_of_a_trailing_newline() {
+        let doc = GovbotDocument::parse(SAMPLE);
+        assert_eq!(doc.to_string(), SAMPLE);
+    }
+}
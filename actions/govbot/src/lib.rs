@@ -0,0 +1,12 @@
+pub mod edit;
+pub mod locale;
+pub mod messages;
+pub mod pipeline;
+pub mod publish;
+pub mod repos;
+pub mod schema;
+pub mod source;
+pub mod suggest;
+pub mod tagging;
+pub mod template;
+pub mod wizard;
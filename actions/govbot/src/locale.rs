@@ -0,0 +1,22 @@
+/// A jurisdiction whose legislation govbot can track: one of the 50 US
+/// states, by its two-letter postal code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WorkingLocale(&'static str);
+
+impl WorkingLocale {
+    /// All jurisdictions govbot knows how to clone/update.
+    pub fn all() -> Vec<WorkingLocale> {
+        ALL_LOCALES.iter().copied().map(WorkingLocale).collect()
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.0
+    }
+}
+
+const ALL_LOCALES: &[&str] = &[
+    "al", "ak", "az", "ar", "ca", "co", "ct", "de", "fl", "ga", "hi", "id", "il", "in", "ia", "ks",
+    "ky", "la", "me", "md", "ma", "mi", "mn", "ms", "mo", "mt", "ne", "nv", "nh", "nj", "nm", "ny",
+    "nc", "nd", "oh", "ok", "or", "pa", "ri", "sc", "sd", "tn", "tx", "ut", "vt", "va", "wa", "wv",
+    "wi", "wy",
+];
@@ -0,0 +1,137 @@
+use anyhow::{bail, Result};
+use std::path::Path;
+
+fn main() -> Result<()> {
+    let raw_args: Vec<String> = std::env::args().skip(1).collect();
+    let lang = raw_args
+        .iter()
+        .position(|a| a == "--lang")
+        .and_then(|i| raw_args.get(i + 1))
+        .cloned();
+    govbot::messages::init(lang.as_deref());
+
+    // Strip --lang before dispatching so it can appear anywhere on the
+    // command line without being mistaken for a subcommand or its argument.
+    let mut args = raw_args.into_iter();
+    let mut rest_args = Vec::new();
+    while let Some(arg) = args.next() {
+        if arg == "--lang" {
+            args.next();
+        } else {
+            rest_args.push(arg);
+        }
+    }
+    let mut args = rest_args.into_iter();
+
+    match args.next().as_deref() {
+        Some("help") | Some("--help") | Some("-h") => {
+            print_help();
+        }
+        Some("schema") => {
+            println!("{}", govbot::schema::generate_pretty());
+        }
+        Some("init") => {
+            let rest: Vec<String> = args.collect();
+            let profile = rest
+                .iter()
+                .position(|a| a == "--profile")
+                .and_then(|i| rest.get(i + 1))
+                .cloned()
+                .unwrap_or_else(|| "researcher".to_string());
+            govbot::wizard::write_default_files(Path::new("."), &profile)?;
+        }
+        Some("suggest") => {
+            govbot::suggest::run_suggest(Path::new("."))?;
+        }
+        Some("add-repo") => {
+            let rest: Vec<String> = args.collect();
+            let Some(repo) = rest.first() else {
+                bail!("Usage: govbot add-repo <repo>");
+            };
+            let config_path = Path::new("govbot.yml");
+            let mut doc = govbot::edit::GovbotDocument::load(config_path)?;
+            doc.add_repo(repo)?;
+            doc.save(config_path)?;
+            println!("Added '{}' to repos:", repo);
+        }
+        Some("remove-repo") => {
+            let rest: Vec<String> = args.collect();
+            let Some(repo) = rest.first() else {
+                bail!("Usage: govbot remove-repo <repo>");
+            };
+            let config_path = Path::new("govbot.yml");
+            let mut doc = govbot::edit::GovbotDocument::load(config_path)?;
+            doc.remove_repo(repo)?;
+            doc.save(config_path)?;
+            println!("Removed '{}' from repos:", repo);
+        }
+        Some("add-tag") => {
+            let rest: Vec<String> = args.collect();
+            let Some(name) = rest.first() else {
+                bail!("Usage: govbot add-tag <name> --description <text> [--example <text> ...]");
+            };
+            let description = rest
+                .iter()
+                .position(|a| a == "--description")
+                .and_then(|i| rest.get(i + 1))
+                .cloned()
+                .unwrap_or_default();
+            let examples: Vec<String> = rest
+                .iter()
+                .enumerate()
+                .filter(|(_, a)| a.as_str() == "--example")
+                .filter_map(|(i, _)| rest.get(i + 1).cloned())
+                .collect();
+            let config_path = Path::new("govbot.yml");
+            let mut doc = govbot::edit::GovbotDocument::load(config_path)?;
+            doc.add_tag(name, &description, &examples)?;
+            doc.save(config_path)?;
+            println!("Added tag '{}'", name);
+        }
+        Some("set-base-url") => {
+            let rest: Vec<String> = args.collect();
+            let Some(url) = rest.first() else {
+                bail!("Usage: govbot set-base-url <url>");
+            };
+            let config_path = Path::new("govbot.yml");
+            let mut doc = govbot::edit::GovbotDocument::load(config_path)?;
+            doc.set_base_url(url)?;
+            doc.save(config_path)?;
+            println!("Updated base_url to '{}'", url);
+        }
+        Some(other) => {
+            bail!("Unknown command: {}. Run 'govbot help' for usage.", other);
+        }
+        None => {
+            let config_path = Path::new("govbot.yml");
+            if config_path.exists() {
+                govbot::pipeline::run_pipeline(config_path)?;
+            } else {
+                govbot::wizard::run_wizard()?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Print the top-level usage summary, including every setup profile
+/// `init --profile` accepts, for `govbot help`/`--help`/non-interactive output.
+fn print_help() {
+    println!("govbot — track and publish tagged state legislation as RSS feeds");
+    println!();
+    println!("USAGE:");
+    println!("  govbot                          Run the pipeline (or the setup wizard if no govbot.yml exists)");
+    println!("  govbot init [--profile NAME]    Write default config files without prompts");
+    println!("  govbot suggest                  Propose tag definitions from cloned bill titles");
+    println!("  govbot schema                   Print the JSON Schema for govbot.yml");
+    println!("  govbot add-repo <repo>          Add a repo entry to govbot.yml");
+    println!("  govbot remove-repo <repo>       Remove a repo entry from govbot.yml");
+    println!("  govbot add-tag <name> --description <text> [--example <text> ...]");
+    println!("                                  Add a tag definition to govbot.yml");
+    println!("  govbot set-base-url <url>       Change build.base_url in govbot.yml");
+    println!("  govbot help                     Show this message");
+    println!();
+    println!("PROFILES (for 'govbot init --profile'):");
+    println!("{}", govbot::wizard::Profile::all_for_help());
+}
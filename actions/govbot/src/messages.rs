@@ -0,0 +1,94 @@
+//! Message catalog for the wizard's user-facing text.
+//!
+//! Catalogs are bundled `key = value` locale files (a simplified Fluent
+//! subset — no plurals or selectors, just one line per message id) selected
+//! via `--lang`/`$LANG`. Every id must exist in `en.ftl`; other locales only
+//! need to override the ids they translate, and anything they don't is
+//! filled in from English so a partial translation never breaks the wizard.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+const EN: &str = include_str!("../locales/en.ftl");
+const ES: &str = include_str!("../locales/es.ftl");
+
+static ACTIVE: OnceLock<Catalog> = OnceLock::new();
+
+/// A fully-resolved set of messages for one locale.
+pub struct Catalog {
+    messages: HashMap<String, String>,
+}
+
+impl Catalog {
+    /// Look up `id`, falling back to the id itself if even English is
+    /// missing it, so a typo'd key is visible rather than a blank string.
+    pub fn get<'a>(&'a self, id: &'a str) -> &'a str {
+        self.messages.get(id).map(String::as_str).unwrap_or(id)
+    }
+
+    /// Look up `id` and substitute `{name}`-style placeholders from `vars`.
+    pub fn get_fmt(&self, id: &str, vars: &[(&str, &str)]) -> String {
+        let mut out = self.get(id).to_string();
+        for (name, value) in vars {
+            out = out.replace(&format!("{{{name}}}"), value);
+        }
+        out
+    }
+}
+
+fn parse(source: &str) -> HashMap<String, String> {
+    source
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| line.split_once('='))
+        .map(|(id, value)| (id.trim().to_string(), value.trim().to_string()))
+        .collect()
+}
+
+fn overlay_for(lang: &str) -> Option<&'static str> {
+    match lang {
+        "es" => Some(ES),
+        _ => None,
+    }
+}
+
+/// Resolve the requested language to its base subtag, e.g. `es_MX.UTF-8` or
+/// `es-MX` both resolve to `es`.
+fn base_subtag(lang: &str) -> String {
+    lang.split(['-', '_', '.'])
+        .next()
+        .unwrap_or(lang)
+        .to_lowercase()
+}
+
+/// Build the catalog for `lang` (a bare subtag like `es`, or a raw `$LANG`
+/// value like `es_MX.UTF-8`), overlaying its locale file (if any) onto the
+/// English baseline. Exposed directly (rather than only through `active()`)
+/// so tests can build a specific locale's catalog without touching the
+/// process-wide active one.
+pub fn load(lang: &str) -> Catalog {
+    let mut messages = parse(EN);
+    if let Some(overlay) = overlay_for(&base_subtag(lang)) {
+        messages.extend(parse(overlay));
+    }
+    Catalog { messages }
+}
+
+/// Set the process-wide active locale from an explicit `--lang` value, or
+/// (if `None`) `$LANG`, falling back to English. Call once, early in
+/// `main`, before any wizard text is generated. Subsequent calls are no-ops,
+/// since the catalog is meant to be fixed for the life of the process.
+pub fn init(explicit_lang: Option<&str>) {
+    let lang = explicit_lang
+        .map(str::to_string)
+        .or_else(|| std::env::var("LANG").ok())
+        .unwrap_or_else(|| "en".to_string());
+    let _ = ACTIVE.set(load(&lang));
+}
+
+/// The active locale's catalog. Defaults to English if `init` was never
+/// called, e.g. in tests that exercise wizard functions directly.
+pub fn active() -> &'static Catalog {
+    ACTIVE.get_or_init(|| load("en"))
+}
@@ -1,16 +1,15 @@
 use anyhow::{Context, Result};
 use std::path::Path;
-use std::process::{Command, Stdio};
+use std::time::Duration;
 
-/// Run the full govbot pipeline: clone/update → tag → build.
+use crate::{publish, repos, source};
+
+/// Run the full govbot pipeline: clone/update → collect bills → tag → build.
 ///
 /// Smart update behavior:
 /// - If `.govbot/repos/` exists with repos: just update existing repos (git pull)
 /// - If `.govbot/repos/` does not exist: clone repos based on govbot.yml config
 pub fn run_pipeline(config_path: &Path) -> Result<()> {
-    let govbot_bin = std::env::current_exe()
-        .context("Failed to determine govbot binary path")?;
-
     let cwd = config_path
         .parent()
         .unwrap_or_else(|| Path::new("."));
@@ -21,117 +20,60 @@ pub fn run_pipeline(config_path: &Path) -> Result<()> {
             .map(|mut d| d.next().is_some())
             .unwrap_or(false);
 
-    // Step 1: Clone or update repos
+    // Step 1: Clone or update repos, in-process via `gix` rather than
+    // shelling out to `git`.
     eprintln!();
-    eprintln!("=== Step 1/3: {} repositories ===", if has_repos { "Updating" } else { "Cloning" });
+    eprintln!("=== Step 1/2: {} repositories ===", if has_repos { "Updating" } else { "Cloning" });
     eprintln!();
 
-    let clone_status = if has_repos {
-        // Update existing repos only
-        Command::new(&govbot_bin)
-            .arg("clone")
-            .current_dir(cwd)
-            .stdin(Stdio::inherit())
-            .stdout(Stdio::inherit())
-            .stderr(Stdio::inherit())
-            .status()
-    } else {
-        // First run: clone based on config
-        let config = crate::publish::load_config(config_path)?;
-        let repos = crate::publish::get_repos_from_config(&config);
-
-        let mut cmd = Command::new(&govbot_bin);
-        cmd.arg("clone");
-        for repo in &repos {
-            cmd.arg(repo);
-        }
-        cmd.current_dir(cwd)
-            .stdin(Stdio::inherit())
-            .stdout(Stdio::inherit())
-            .stderr(Stdio::inherit())
-            .status()
-    };
-
-    match clone_status {
-        Ok(status) if !status.success() => {
-            eprintln!("⚠️  Clone/update had errors (continuing anyway)");
-        }
-        Err(e) => {
-            eprintln!("⚠️  Failed to run clone: {} (continuing anyway)", e);
-        }
-        _ => {}
+    let config = crate::publish::load_config(config_path)?;
+    let timeouts = &config.build.timeouts;
+
+    let report = repos::sync_all(
+        &config.repos,
+        &repos_dir,
+        Duration::from_secs(timeouts.clone_secs),
+        timeouts.retries,
+        source::source_for,
+        |line| eprintln!("  {}", line),
+    );
+    if !report.retried.is_empty() {
+        eprintln!("⚠️  Retried: {}", report.retried.join(", "));
     }
-
-    // Step 2: Tag bills (govbot logs | govbot tag)
-    eprintln!();
-    eprintln!("=== Step 2/3: Tagging bills ===");
-    eprintln!();
-
-    let tag_result = run_logs_pipe_tag(&govbot_bin, cwd);
-    match tag_result {
-        Ok(false) => {
-            eprintln!("⚠️  Tagging had errors (continuing anyway)");
-        }
-        Err(e) => {
-            eprintln!("⚠️  Failed to run tagging: {} (continuing anyway)", e);
-        }
-        _ => {}
+    for (name, err) in &report.failures {
+        eprintln!("⚠️  {}: {:#}", name, err);
+    }
+    if !report.failures.is_empty() {
+        eprintln!(
+            "⚠️  {} of {} repositories failed to sync (continuing with the rest)",
+            report.failures.len(),
+            config.repos.len()
+        );
     }
 
-    // Step 3: Build RSS feeds
+    // Step 2: Collect bills, tag them, and render the RSS feed — all
+    // in-process, since `publish::publish_feed` already runs tagging itself.
+    // (Earlier versions re-invoked this binary as `govbot logs`/`tag`/`build`
+    // subprocesses, but those subcommands never existed and the pipe between
+    // them was never wired to the build step either — this replaces all of
+    // that with a single, real in-process call.)
     eprintln!();
-    eprintln!("=== Step 3/3: Building RSS feeds ===");
+    eprintln!("=== Step 2/2: Tagging and building RSS feed ===");
     eprintln!();
 
-    let build_status = Command::new(&govbot_bin)
-        .arg("build")
-        .current_dir(cwd)
-        .stdin(Stdio::inherit())
-        .stdout(Stdio::inherit())
-        .stderr(Stdio::inherit())
-        .status()
-        .context("Failed to run govbot build")?;
+    let items = repos::collect_items(&config.repos, &repos_dir, &mut |line| eprintln!("  {}", line));
+    eprintln!("  collected {} bill(s)", items.len());
 
-    if !build_status.success() {
-        anyhow::bail!("Build step failed with exit code: {}", build_status.code().unwrap_or(-1));
-    }
+    let output_dir = cwd.join(&config.build.output_dir);
+    std::fs::create_dir_all(&output_dir)
+        .with_context(|| format!("Failed to create output directory: {}", output_dir.display()))?;
+    let output_path = output_dir.join(&config.build.output_file);
+
+    publish::publish_feed(&config, items, &output_path)?;
+    eprintln!("  wrote {}", output_path.display());
 
     eprintln!();
     eprintln!("Pipeline complete!");
 
     Ok(())
 }
-
-/// Run `govbot logs | govbot tag` by piping stdout of logs into stdin of tag.
-/// Returns Ok(true) if both succeeded, Ok(false) if either failed.
-fn run_logs_pipe_tag(govbot_bin: &Path, cwd: &Path) -> Result<bool> {
-    let mut logs_child = Command::new(govbot_bin)
-        .arg("logs")
-        .current_dir(cwd)
-        .stdout(Stdio::piped())
-        .stderr(Stdio::inherit())
-        .spawn()
-        .context("Failed to spawn govbot logs")?;
-
-    let logs_stdout = logs_child
-        .stdout
-        .take()
-        .context("Failed to capture logs stdout")?;
-
-    let tag_child = Command::new(govbot_bin)
-        .arg("tag")
-        .current_dir(cwd)
-        .stdin(logs_stdout)
-        .stdout(Stdio::inherit())
-        .stderr(Stdio::inherit())
-        .spawn()
-        .context("Failed to spawn govbot tag")?;
-
-    let tag_output = tag_child
-        .wait_with_output()
-        .context("Failed to wait for govbot tag")?;
-
-    let logs_status = logs_child.wait().context("Failed to wait for govbot logs")?;
-
-    Ok(logs_status.success() && tag_output.status.success())
-}
@@ -0,0 +1,298 @@
+use anyhow::{bail, Context, Result};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::tagging;
+use crate::template::{Template, DEFAULT_TEMPLATE};
+
+/// A single piece of legislation pulled from a cloned repo, ready to be
+/// rendered into the published feed.
+///
+/// Also the shape each bill JSON file written by a [`crate::source::DataSource`]
+/// is expected to deserialize into; `tags` is always empty at that point and
+/// only gets filled in by `tag_items` during `publish_feed`.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct LegislationItem {
+    pub title: String,
+    pub summary: String,
+    pub url: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub introduced_date: Option<String>,
+    #[serde(default)]
+    pub state: Option<String>,
+}
+
+/// One entry of the `repos:` list: either a bare jurisdiction name (the
+/// common case, defaulting to a git source) or a detailed form that picks a
+/// non-git [`crate::source::DataSource`] via `type:`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum RepoEntry {
+    Name(String),
+    Detailed(RepoDetail),
+}
+
+/// The detailed form of a `repos:` entry, used to track a jurisdiction that
+/// doesn't publish a git mirror.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct RepoDetail {
+    pub name: String,
+    /// Which `DataSource` fetches this entry: "git" (default), "http", or
+    /// "local".
+    #[serde(rename = "type", default = "default_source_kind")]
+    pub kind: String,
+    /// Overrides the source's default location (the git mirror URL, the API
+    /// endpoint, or the local directory path) when the default derived from
+    /// `name` isn't right.
+    #[serde(default)]
+    pub url: Option<String>,
+}
+
+fn default_source_kind() -> String {
+    "git".to_string()
+}
+
+impl RepoEntry {
+    pub fn name(&self) -> &str {
+        match self {
+            RepoEntry::Name(name) => name,
+            RepoEntry::Detailed(detail) => &detail.name,
+        }
+    }
+
+    pub fn kind(&self) -> &str {
+        match self {
+            RepoEntry::Name(_) => "git",
+            RepoEntry::Detailed(detail) => &detail.kind,
+        }
+    }
+
+    pub fn url(&self) -> Option<&str> {
+        match self {
+            RepoEntry::Name(_) => None,
+            RepoEntry::Detailed(detail) => detail.url.as_deref(),
+        }
+    }
+}
+
+impl From<&str> for RepoEntry {
+    fn from(name: &str) -> Self {
+        RepoEntry::Name(name.to_string())
+    }
+}
+
+impl From<String> for RepoEntry {
+    fn from(name: String) -> Self {
+        RepoEntry::Name(name)
+    }
+}
+
+/// A tag definition as written under the `tags:` block of govbot.yml.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct TagDefinition {
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub examples: Vec<String>,
+}
+
+/// The `build:` block of govbot.yml.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct BuildConfig {
+    /// Absolute URL the published feeds will be served from.
+    #[schemars(regex(pattern = r"^https?://"))]
+    pub base_url: String,
+    #[serde(default = "default_output_dir")]
+    pub output_dir: String,
+    #[serde(default = "default_output_file")]
+    pub output_file: String,
+    #[serde(default)]
+    pub timeouts: TimeoutsConfig,
+}
+
+fn default_output_dir() -> String {
+    "docs".to_string()
+}
+
+fn default_output_file() -> String {
+    "feed.xml".to_string()
+}
+
+/// The `build.timeouts:` block of govbot.yml — how long the clone/update
+/// stage is allowed to run per repo before it's killed, and how many times
+/// it's retried (with exponential backoff) before giving up on it.
+///
+/// Tagging and rendering the feed run in-process with no subprocess to kill
+/// and nothing in them checks a cancellation flag, so there's no knob here
+/// for those stages — only the stage that actually enforces one.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct TimeoutsConfig {
+    #[serde(default = "default_clone_secs")]
+    pub clone_secs: u64,
+    #[serde(default = "default_retries")]
+    pub retries: u32,
+}
+
+fn default_clone_secs() -> u64 {
+    300
+}
+
+fn default_retries() -> u32 {
+    3
+}
+
+impl Default for TimeoutsConfig {
+    fn default() -> Self {
+        TimeoutsConfig {
+            clone_secs: default_clone_secs(),
+            retries: default_retries(),
+        }
+    }
+}
+
+/// The `tagging:` block of govbot.yml.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct TaggingConfig {
+    #[serde(default = "default_threshold")]
+    pub threshold: f64,
+    #[serde(default = "default_embedder")]
+    pub embedder: String,
+}
+
+fn default_threshold() -> f64 {
+    0.2
+}
+
+fn default_embedder() -> String {
+    "tfidf".to_string()
+}
+
+impl Default for TaggingConfig {
+    fn default() -> Self {
+        TaggingConfig {
+            threshold: default_threshold(),
+            embedder: default_embedder(),
+        }
+    }
+}
+
+/// The fully typed contents of govbot.yml.
+///
+/// Replaces ad-hoc `serde_json::Value` walking: every field is known at
+/// compile time, and `validate()` reports precise, user-facing errors for
+/// anything a wizard-generated (or hand-edited) file gets wrong.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct GovbotConfig {
+    #[serde(default)]
+    pub repos: Vec<RepoEntry>,
+    #[serde(default)]
+    pub tags: HashMap<String, TagDefinition>,
+    pub build: BuildConfig,
+    #[serde(default)]
+    pub tagging: TaggingConfig,
+    /// Custom feed-item template; falls back to `template::DEFAULT_TEMPLATE`
+    /// when absent so existing output is unchanged.
+    pub template: Option<String>,
+}
+
+impl GovbotConfig {
+    /// Check the config for problems a wizard-generated or hand-edited file
+    /// might have, returning a single descriptive error for the first one
+    /// found.
+    pub fn validate(&self) -> Result<()> {
+        if self.repos.is_empty() {
+            bail!("repos must not be empty");
+        }
+
+        for entry in &self.repos {
+            if !matches!(entry.kind(), "git" | "http" | "local") {
+                bail!(
+                    "repos entry '{}' has unknown type '{}' (expected git, http, or local)",
+                    entry.name(),
+                    entry.kind()
+                );
+            }
+            if matches!(entry.kind(), "http" | "local") && entry.url().is_none() {
+                bail!(
+                    "repos entry '{}' has type '{}' but no url (there is no default {} for a jurisdiction name)",
+                    entry.name(),
+                    entry.kind(),
+                    if entry.kind() == "http" { "API endpoint" } else { "directory" }
+                );
+            }
+        }
+
+        if !self.build.base_url.starts_with("http://") && !self.build.base_url.starts_with("https://") {
+            bail!("build.base_url must be an absolute URL (got '{}')", self.build.base_url);
+        }
+
+        for (name, tag) in &self.tags {
+            if tag.description.trim().is_empty() {
+                bail!("tag '{}' is missing description", name);
+            }
+            if tag.examples.is_empty() {
+                bail!("tag '{}' must have at least one example", name);
+            }
+        }
+
+        if !(0.0..=1.0).contains(&self.tagging.threshold) {
+            bail!("tagging.threshold must be between 0.0 and 1.0 (got {})", self.tagging.threshold);
+        }
+
+        Template::compile(self.template.as_deref().unwrap_or(DEFAULT_TEMPLATE))
+            .context("invalid template")?;
+
+        Ok(())
+    }
+
+    /// The compiled feed-item template, falling back to the built-in default.
+    fn template(&self) -> Template {
+        Template::compile(self.template.as_deref().unwrap_or(DEFAULT_TEMPLATE))
+            .expect("template was validated in GovbotConfig::validate")
+    }
+}
+
+/// Load, parse, and validate `govbot.yml` from `path`.
+pub fn load_config(path: &Path) -> Result<GovbotConfig> {
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read config file: {}", path.display()))?;
+    let config: GovbotConfig = serde_yaml::from_str(&contents)
+        .with_context(|| format!("Failed to parse config file: {}", path.display()))?;
+    config.validate()?;
+    Ok(config)
+}
+
+/// Pull the jurisdiction names out of a parsed config's `repos:` list.
+pub fn get_repos_from_config(config: &GovbotConfig) -> Vec<String> {
+    config.repos.iter().map(|entry| entry.name().to_string()).collect()
+}
+
+/// Run the auto-tagging step over `items`, mutating each item's `tags` in
+/// place, then render the resulting feed.xml to `output_path`.
+pub fn publish_feed(config: &GovbotConfig, items: Vec<LegislationItem>, output_path: &Path) -> Result<()> {
+    let mut items = items;
+    tagging::tag_items(config, &mut items)?;
+
+    let feed_xml = render_feed(&items, &config.template());
+    fs::write(output_path, feed_xml)
+        .with_context(|| format!("Failed to write feed: {}", output_path.display()))?;
+
+    Ok(())
+}
+
+/// Render items into a minimal RSS 2.0 feed using the compiled item template.
+fn render_feed(items: &[LegislationItem], template: &Template) -> String {
+    let mut out = String::new();
+    out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str("<rss version=\"2.0\">\n  <channel>\n");
+    for item in items {
+        out.push_str(&template.render(item));
+    }
+    out.push_str("  </channel>\n</rss>\n");
+    out
+}
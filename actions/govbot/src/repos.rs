@@ -0,0 +1,174 @@
+use anyhow::{bail, Result};
+use std::fs;
+use std::path::Path;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use crate::publish::{LegislationItem, RepoEntry};
+use crate::source::{self, DataSource};
+
+/// The default git URL for a jurisdiction repo tracked by govbot.yml's
+/// `repos:` list, used when an entry doesn't specify its own `url:`.
+pub fn repo_url(name: &str) -> String {
+    format!("https://github.com/openstates/openstates-scrapers-{}", name)
+}
+
+/// The outcome of [`sync_all`]: which repos ultimately failed (after
+/// exhausting retries) and which ones needed at least one retry to succeed,
+/// so the pipeline can print an actionable summary instead of going quiet.
+#[derive(Debug, Default)]
+pub struct SyncReport {
+    pub failures: Vec<(String, anyhow::Error)>,
+    pub retried: Vec<String>,
+}
+
+/// How much longer, past `timeout`, we give `op` to unwind cooperatively
+/// (via the `gix` interrupt flag) before giving up on it and returning
+/// anyway. A source that doesn't check the interrupt flag at all (e.g. a
+/// stuck HTTP read) never gets to unwind at all within this window, but we
+/// still return a timeout error instead of blocking forever — the op's
+/// thread is simply abandoned to finish (or not) on its own.
+const INTERRUPT_GRACE: Duration = Duration::from_secs(5);
+
+/// Run `op` on its own thread and wait up to `timeout` for it to finish,
+/// forwarding any progress lines it sends through `progress_tx` to
+/// `on_progress` as they arrive.
+///
+/// `gix` checks `gix::interrupt::IS_INTERRUPTED` cooperatively while it
+/// works, so on timeout we trip that flag to ask the stuck operation to
+/// unwind, then reset it once it has so later repos aren't affected. Ops
+/// that don't cooperate with that flag (anything other than `GitSource`)
+/// are expected to bound their own blocking calls instead; either way, this
+/// function itself never waits past `timeout + INTERRUPT_GRACE`.
+fn run_with_timeout<T: Send + 'static>(
+    timeout: Duration,
+    on_progress: &mut impl FnMut(&str),
+    op: impl FnOnce(mpsc::Sender<String>) -> Result<T> + Send + 'static,
+) -> Result<T> {
+    let (result_tx, result_rx) = mpsc::channel();
+    let (progress_tx, progress_rx) = mpsc::channel::<String>();
+
+    thread::spawn(move || {
+        let _ = result_tx.send(op(progress_tx));
+    });
+
+    let deadline = std::time::Instant::now() + timeout;
+    loop {
+        match progress_rx.recv_timeout(Duration::from_millis(100)) {
+            Ok(line) => on_progress(&line),
+            Err(mpsc::RecvTimeoutError::Timeout) => {}
+            Err(mpsc::RecvTimeoutError::Disconnected) => {}
+        }
+
+        if let Ok(result) = result_rx.try_recv() {
+            while let Ok(line) = progress_rx.try_recv() {
+                on_progress(&line);
+            }
+            return result;
+        }
+
+        if std::time::Instant::now() >= deadline {
+            gix::interrupt::trigger();
+            let _ = result_rx.recv_timeout(INTERRUPT_GRACE);
+            gix::interrupt::reset();
+            bail!("timed out after {:?}", timeout);
+        }
+    }
+}
+
+/// Fetch or refresh every `repos:` entry under `repos_dir`, continuing past
+/// individual failures so one bad jurisdiction doesn't block the rest.
+///
+/// Each entry is dispatched to the [`DataSource`] `source_for` builds for it
+/// (ordinarily [`source::source_for`], git by default or whatever `type:`
+/// picks; tests pass a fake to exercise the timeout/retry behavior below
+/// without touching the network) and given `timeout` to finish; an entry
+/// that times out or otherwise fails is retried up to `retries` times with
+/// exponential backoff (1s, 2s, 4s, ...) before it's recorded as a failure.
+/// Every error carries full `anyhow` context rather than the opaque
+/// "continuing anyway" of the old subprocess-based pipeline.
+pub fn sync_all(
+    entries: &[RepoEntry],
+    repos_dir: &Path,
+    timeout: Duration,
+    retries: u32,
+    source_for: impl Fn(&RepoEntry) -> Box<dyn DataSource>,
+    mut on_progress: impl FnMut(&str),
+) -> SyncReport {
+    let mut report = SyncReport::default();
+
+    for entry in entries {
+        let name = entry.name().to_string();
+        let dest = repos_dir.join(&name);
+        let mut attempt = 0;
+        let result = loop {
+            let dest = dest.clone();
+            let source: Box<dyn DataSource> = source_for(entry);
+            let result = run_with_timeout(timeout, &mut on_progress, move |progress_tx| {
+                let mut on_progress = move |line: &str| {
+                    let _ = progress_tx.send(line.to_string());
+                };
+                source.fetch(&dest, &mut on_progress)
+            });
+
+            match result {
+                Ok(()) => break Ok(()),
+                Err(err) if attempt < retries => {
+                    attempt += 1;
+                    report.retried.push(name.clone());
+                    let backoff = Duration::from_secs(1 << (attempt - 1));
+                    on_progress(&format!(
+                        "{} failed ({:#}), retrying in {:?} (attempt {}/{})",
+                        name, err, backoff, attempt, retries
+                    ));
+                    thread::sleep(backoff);
+                }
+                Err(err) => break Err(err),
+            }
+        };
+
+        match result {
+            Ok(()) => on_progress(&format!("synced {}", name)),
+            Err(err) => report.failures.push((name.clone(), err)),
+        }
+    }
+
+    report
+}
+
+/// Read every bill file each `repos:` entry has made available under
+/// `repos_dir` (after [`sync_all`]) and parse it into a [`LegislationItem`],
+/// skipping (and reporting) any file that isn't valid JSON in the expected
+/// shape rather than failing the whole run over one bad bill.
+pub fn collect_items(
+    entries: &[RepoEntry],
+    repos_dir: &Path,
+    on_progress: &mut impl FnMut(&str),
+) -> Vec<LegislationItem> {
+    let mut items = Vec::new();
+
+    for entry in entries {
+        let dest = repos_dir.join(entry.name());
+        let source = source::source_for(entry);
+        let files = match source.entries(&dest) {
+            Ok(files) => files,
+            Err(err) => {
+                on_progress(&format!("failed to list bills for {}: {:#}", entry.name(), err));
+                continue;
+            }
+        };
+
+        for file in files {
+            let parsed = fs::read_to_string(&file)
+                .ok()
+                .and_then(|contents| serde_json::from_str::<LegislationItem>(&contents).ok());
+            match parsed {
+                Some(item) => items.push(item),
+                None => on_progress(&format!("skipping malformed bill file: {}", file.display())),
+            }
+        }
+    }
+
+    items
+}
@@ -0,0 +1,16 @@
+use schemars::schema_for;
+
+use crate::publish::GovbotConfig;
+
+/// Generate a JSON Schema describing govbot.yml, derived directly from
+/// `GovbotConfig` via `#[derive(JsonSchema)]` so the schema can never drift
+/// from what `load_config` actually accepts.
+pub fn generate() -> serde_json::Value {
+    let schema = schema_for!(GovbotConfig);
+    serde_json::to_value(schema).expect("schema serializes to JSON")
+}
+
+/// Render the schema as pretty-printed JSON, as shown by `govbot schema`.
+pub fn generate_pretty() -> String {
+    serde_json::to_string_pretty(&generate()).expect("schema serializes to JSON")
+}
@@ -0,0 +1,222 @@
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::publish::RepoEntry;
+
+/// Upper bound on a single HTTP request/response round trip for
+/// [`HttpSource`]. `ureq` has no timeout by default — without one, a
+/// jurisdiction whose API never responds would hang its fetch forever,
+/// no matter what `build.timeouts.clone_secs` is configured to.
+const HTTP_REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// A place govbot can pull a jurisdiction's legislative data from.
+///
+/// Modeled on the "DVCS backend implements a `Backend` trait" split used by
+/// forge's build.rs: every concrete source only needs to know how to fetch
+/// itself and list what it found at `dest`, and callers (the Step 1/2
+/// pipeline stage, `repos::sync_all`) drive all of them the same way
+/// regardless of whether the data came from git, an HTTP API, or disk.
+pub trait DataSource: Send + Sync {
+    /// Pull (or refresh) this source's data into `dest`, reporting progress
+    /// through `on_progress` as it goes.
+    fn fetch(&self, dest: &Path, on_progress: &mut dyn FnMut(&str)) -> Result<()>;
+
+    /// List the bill/document files this source has made available at
+    /// `dest` so far.
+    fn entries(&self, dest: &Path) -> Result<Vec<PathBuf>>;
+}
+
+/// A jurisdiction tracked via a git mirror (e.g. an openstates-scrapers
+/// repo), cloned shallowly and fast-forwarded in-process via `gix`.
+pub struct GitSource {
+    url: String,
+}
+
+impl GitSource {
+    pub fn new(url: String) -> Self {
+        GitSource { url }
+    }
+}
+
+impl DataSource for GitSource {
+    fn fetch(&self, dest: &Path, on_progress: &mut dyn FnMut(&str)) -> Result<()> {
+        fetch_via_staging(&self.url, dest, on_progress)
+    }
+
+    fn entries(&self, dest: &Path) -> Result<Vec<PathBuf>> {
+        list_json_files(dest)
+    }
+}
+
+/// A jurisdiction whose bills are published as a JSON array behind an
+/// OpenStates-style HTTP API rather than a git mirror.
+pub struct HttpSource {
+    url: String,
+}
+
+impl HttpSource {
+    pub fn new(url: String) -> Self {
+        HttpSource { url }
+    }
+}
+
+impl DataSource for HttpSource {
+    fn fetch(&self, dest: &Path, on_progress: &mut dyn FnMut(&str)) -> Result<()> {
+        on_progress(&format!("fetching {}", self.url));
+
+        let agent = ureq::AgentBuilder::new().timeout(HTTP_REQUEST_TIMEOUT).build();
+        let body = agent
+            .get(&self.url)
+            .call()
+            .with_context(|| format!("failed to fetch {}", self.url))?
+            .into_string()
+            .with_context(|| format!("failed to read response body from {}", self.url))?;
+
+        let bills: Vec<serde_json::Value> = serde_json::from_str(&body)
+            .with_context(|| format!("{} did not return a JSON array of bills", self.url))?;
+
+        fs::create_dir_all(dest).with_context(|| format!("failed to create {}", dest.display()))?;
+
+        for (i, bill) in bills.iter().enumerate() {
+            let path = dest.join(format!("{}.json", i));
+            let contents = serde_json::to_string_pretty(bill)
+                .with_context(|| format!("failed to serialize bill {} from {}", i, self.url))?;
+            fs::write(&path, contents).with_context(|| format!("failed to write {}", path.display()))?;
+        }
+
+        on_progress(&format!("fetched {} bills from {}", bills.len(), self.url));
+        Ok(())
+    }
+
+    fn entries(&self, dest: &Path) -> Result<Vec<PathBuf>> {
+        list_json_files(dest)
+    }
+}
+
+/// A source that's already present on disk — no fetch step, just a
+/// directory of bill files. Used for offline development and tests.
+pub struct LocalDirSource {
+    path: PathBuf,
+}
+
+impl LocalDirSource {
+    pub fn new(path: PathBuf) -> Self {
+        LocalDirSource { path }
+    }
+}
+
+impl DataSource for LocalDirSource {
+    fn fetch(&self, _dest: &Path, on_progress: &mut dyn FnMut(&str)) -> Result<()> {
+        if !self.path.exists() {
+            bail!("local source directory {} does not exist", self.path.display());
+        }
+        on_progress(&format!("using local directory {}", self.path.display()));
+        Ok(())
+    }
+
+    fn entries(&self, _dest: &Path) -> Result<Vec<PathBuf>> {
+        list_json_files(&self.path)
+    }
+}
+
+fn list_json_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut files: Vec<PathBuf> = fs::read_dir(dir)
+        .with_context(|| format!("failed to read {}", dir.display()))?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+/// Build the concrete [`DataSource`] a `repos:` entry refers to, dispatching
+/// on its `type:` discriminator ("git" by default, so plain repo names keep
+/// working unchanged).
+pub fn source_for(entry: &RepoEntry) -> Box<dyn DataSource> {
+    match entry.kind() {
+        // "http"/"local" have no sensible default location the way "git"
+        // does (there's no such thing as "the" API endpoint or directory
+        // for a jurisdiction name) — `GovbotConfig::validate` rejects a
+        // config missing `url:` for either before this is ever called, so
+        // an empty string here is unreachable in practice, not a silent
+        // fallback to guess at.
+        "http" => Box::new(HttpSource::new(entry.url().unwrap_or_default().to_string())),
+        "local" => Box::new(LocalDirSource::new(PathBuf::from(entry.url().unwrap_or_default()))),
+        _ => Box::new(GitSource::new(
+            entry
+                .url()
+                .map(str::to_string)
+                .unwrap_or_else(|| crate::repos::repo_url(entry.name())),
+        )),
+    }
+}
+
+/// Clone `url` into `dest` as a shallow (depth 1) clone, using `gix`
+/// in-process rather than shelling out to a `git` binary.
+fn clone_repo(url: &str, dest: &Path, mut on_progress: impl FnMut(&str)) -> Result<()> {
+    on_progress(&format!("cloning {}", url));
+
+    let mut prepare = gix::prepare_clone(url, dest)
+        .with_context(|| format!("failed to prepare clone of {}", url))?
+        .with_shallow(gix::remote::fetch::Shallow::DepthAtRemote(
+            std::num::NonZeroU32::new(1).expect("1 is non-zero"),
+        ));
+
+    let (mut checkout, _outcome) = prepare
+        .fetch_then_checkout(gix::progress::Discard, &gix::interrupt::IS_INTERRUPTED)
+        .with_context(|| format!("failed to fetch {}", url))?;
+
+    checkout
+        .main_worktree(gix::progress::Discard, &gix::interrupt::IS_INTERRUPTED)
+        .with_context(|| format!("failed to check out working tree for {}", url))?;
+
+    on_progress(&format!("cloned {}", url));
+    Ok(())
+}
+
+/// Clone `url` into a scratch directory next to `dest` and atomically swap it
+/// into place, refreshing `dest` whether it's a brand new clone or a refresh
+/// of an existing one (the in-process equivalent of `git pull --ff-only`).
+///
+/// Since these are shallow (depth 1) mirrors to begin with, there's no local
+/// history to fast-forward against — the cheapest correct way to pick up new
+/// commits is to discard the old shallow clone and make a fresh one, rather
+/// than hand-rolling gix's fetch-then-checkout machinery a second time.
+///
+/// Cloning into a scratch directory first (rather than straight into `dest`)
+/// matters when [`run_with_timeout`](crate::repos) gives up on a stuck
+/// attempt and `sync_all` retries: the abandoned attempt's thread may still
+/// be running and writing to whatever path it was given. Each attempt gets
+/// its own scratch directory, so a straggler can only ever race the final
+/// swap into `dest`, not the (possibly minutes-long) clone itself.
+fn fetch_via_staging(url: &str, dest: &Path, on_progress: &mut dyn FnMut(&str)) -> Result<()> {
+    let staging = staging_dir_for(dest);
+    let result = clone_repo(url, &staging, &mut *on_progress);
+    if result.is_err() {
+        let _ = fs::remove_dir_all(&staging);
+        return result;
+    }
+
+    if dest.exists() {
+        fs::remove_dir_all(dest)
+            .with_context(|| format!("failed to remove stale clone at {}", dest.display()))?;
+    }
+    fs::rename(&staging, dest)
+        .with_context(|| format!("failed to move freshly cloned repo into {}", dest.display()))
+}
+
+/// A scratch directory next to `dest`, unique per call, for [`fetch_via_staging`]
+/// to clone into before swapping the result into place.
+fn staging_dir_for(dest: &Path) -> PathBuf {
+    static COUNTER: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+    let id = COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    let name = dest.file_name().unwrap_or_default().to_string_lossy();
+    dest.with_file_name(format!(".{}.tmp-{}-{}", name, std::process::id(), id))
+}
@@ -0,0 +1,217 @@
+use anyhow::{Context, Result};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+use crate::publish::GovbotConfig;
+
+/// Words common enough in bill titles across every jurisdiction that they'd
+/// dominate every cluster if kept; dropped before clustering.
+const STOPWORDS: &[&str] = &[
+    "the", "a", "an", "of", "to", "for", "and", "or", "in", "on", "by", "with", "act", "bill",
+    "relating", "concerning", "regarding", "amending", "providing", "establishing", "certain",
+];
+
+const MIN_TITLE_LENGTH: usize = 3;
+const MAX_SUGGESTIONS: usize = 5;
+const MAX_EXAMPLES: usize = 3;
+
+/// A candidate tag the user doesn't have configured yet, generated by
+/// clustering co-occurring keywords across cloned bill titles.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagSuggestion {
+    pub name: String,
+    pub description: String,
+    pub examples: Vec<String>,
+    pub include_keywords: Vec<String>,
+}
+
+impl TagSuggestion {
+    /// Render this suggestion as a ready-to-paste YAML block, in the same
+    /// shape as the example `education` tag in `generate_govbot_yml` plus
+    /// the `include_keywords` hint from `ai_prompt_template`.
+    pub fn to_yaml_block(&self) -> String {
+        let mut out = String::new();
+        out.push_str(&format!("{}:\n", self.name));
+        out.push_str("  description: |\n");
+        out.push_str(&format!("    {}\n", self.description));
+        out.push_str("  examples:\n");
+        for example in &self.examples {
+            out.push_str(&format!("    - \"{}\"\n", escape_yaml_double_quoted(example)));
+        }
+        out.push_str("  include_keywords:\n");
+        for keyword in &self.include_keywords {
+            out.push_str(&format!("    - \"{}\"\n", escape_yaml_double_quoted(keyword)));
+        }
+        out
+    }
+}
+
+/// Escape `\` and `"` so `s` can be safely interpolated into a double-quoted
+/// YAML scalar. Bill titles (the source of both `examples` and, via
+/// tokenization, `include_keywords`) are scraped text and routinely contain
+/// embedded quotes, e.g. `the "South Carolina Enterprise Zone Act"`.
+fn escape_yaml_double_quoted(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn tokenize(title: &str) -> HashSet<String> {
+    title
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|term| term.len() >= MIN_TITLE_LENGTH && !STOPWORDS.contains(term))
+        .map(|term| term.to_string())
+        .collect()
+}
+
+/// Read every bill title found in the JSON files under `repos_dir`
+/// (recursively), skipping files that aren't JSON objects with a `title`
+/// field. Scraper output formats vary by jurisdiction, so any other field
+/// is simply ignored rather than treated as an error.
+fn collect_titles(repos_dir: &Path) -> Result<Vec<String>> {
+    let mut titles = Vec::new();
+    if !repos_dir.exists() {
+        return Ok(titles);
+    }
+    collect_titles_in(repos_dir, &mut titles)?;
+    Ok(titles)
+}
+
+fn collect_titles_in(dir: &Path, titles: &mut Vec<String>) -> Result<()> {
+    for entry in fs::read_dir(dir).with_context(|| format!("failed to read {}", dir.display()))? {
+        let entry = entry.with_context(|| format!("failed to read an entry in {}", dir.display()))?;
+        let path = entry.path();
+        if path.is_dir() {
+            collect_titles_in(&path, titles)?;
+            continue;
+        }
+        if path.extension().is_some_and(|ext| ext == "json") {
+            let Ok(contents) = fs::read_to_string(&path) else { continue };
+            let Ok(value) = serde_json::from_str::<serde_json::Value>(&contents) else { continue };
+            if let Some(title) = value.get("title").and_then(|t| t.as_str()) {
+                titles.push(title.to_string());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Cluster the keywords in `titles` by co-occurrence and propose a tag for
+/// each cluster whose name isn't already in `existing_tags`.
+///
+/// Terms appearing in fewer than two titles (too rare to cluster) or more
+/// than half of all titles (too generic to be useful) are dropped first.
+/// Clusters are formed greedily from the most frequently co-occurring
+/// keyword pairs, each keyword used by at most one cluster.
+pub fn suggest_tags(titles: &[String], existing_tags: &HashSet<String>) -> Vec<TagSuggestion> {
+    let total = titles.len();
+    if total < 2 {
+        return Vec::new();
+    }
+
+    let title_terms: Vec<HashSet<String>> = titles.iter().map(|t| tokenize(t)).collect();
+
+    let mut doc_freq: HashMap<&str, usize> = HashMap::new();
+    for terms in &title_terms {
+        for term in terms {
+            *doc_freq.entry(term.as_str()).or_insert(0) += 1;
+        }
+    }
+
+    let kept_terms: HashSet<&str> = doc_freq
+        .iter()
+        .filter(|(_, &freq)| freq >= 2 && freq * 2 <= total)
+        .map(|(term, _)| *term)
+        .collect();
+
+    let mut pair_freq: HashMap<(String, String), usize> = HashMap::new();
+    for terms in &title_terms {
+        let mut kept: Vec<&str> = terms.iter().map(String::as_str).filter(|t| kept_terms.contains(t)).collect();
+        kept.sort_unstable();
+        for i in 0..kept.len() {
+            for j in (i + 1)..kept.len() {
+                *pair_freq.entry((kept[i].to_string(), kept[j].to_string())).or_insert(0) += 1;
+            }
+        }
+    }
+
+    let mut pairs: Vec<((String, String), usize)> = pair_freq.into_iter().filter(|(_, freq)| *freq >= 2).collect();
+    pairs.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+    let mut used_terms: HashSet<String> = HashSet::new();
+    let mut suggestions = Vec::new();
+
+    for ((a, b), _freq) in pairs {
+        if used_terms.contains(&a) || used_terms.contains(&b) {
+            continue;
+        }
+
+        let name = slugify(&a);
+        if existing_tags.contains(&name) {
+            used_terms.insert(a);
+            used_terms.insert(b);
+            continue;
+        }
+
+        let keywords = vec![a.clone(), b.clone()];
+
+        let mut scored: Vec<(usize, &String)> = titles
+            .iter()
+            .zip(&title_terms)
+            .filter(|(_, terms)| terms.contains(&a) && terms.contains(&b))
+            .map(|(title, terms)| (keywords.iter().filter(|k| terms.contains(*k)).count(), title))
+            .collect();
+        scored.sort_by_key(|y| std::cmp::Reverse(y.0));
+
+        let examples: Vec<String> = scored.into_iter().take(MAX_EXAMPLES).map(|(_, title)| title.clone()).collect();
+
+        suggestions.push(TagSuggestion {
+            name,
+            description: format!("Legislation whose titles mention both \"{}\" and \"{}\".", a, b),
+            examples,
+            include_keywords: keywords,
+        });
+
+        used_terms.insert(a);
+        used_terms.insert(b);
+
+        if suggestions.len() >= MAX_SUGGESTIONS {
+            break;
+        }
+    }
+
+    suggestions
+}
+
+fn slugify(term: &str) -> String {
+    term.chars().map(|c| if c.is_alphanumeric() { c } else { '_' }).collect()
+}
+
+/// Scan `.govbot/repos/` under `cwd` and print any tag suggestions not
+/// already present in `govbot.yml`, as ready-to-paste YAML blocks.
+pub fn run_suggest(cwd: &Path) -> Result<()> {
+    let config_path = cwd.join("govbot.yml");
+    let existing_tags: HashSet<String> = if config_path.exists() {
+        let config: GovbotConfig = crate::publish::load_config(&config_path)?;
+        config.tags.keys().cloned().collect()
+    } else {
+        HashSet::new()
+    };
+
+    let repos_dir = cwd.join(".govbot").join("repos");
+    let titles = collect_titles(&repos_dir)?;
+    let suggestions = suggest_tags(&titles, &existing_tags);
+
+    if suggestions.is_empty() {
+        println!("No new tag suggestions found in {} bill titles.", titles.len());
+        return Ok(());
+    }
+
+    println!("# Paste whichever of these you want under the 'tags:' section of govbot.yml.");
+    println!("# Generated from {} bill titles under {}.\n", titles.len(), repos_dir.display());
+    for suggestion in &suggestions {
+        println!("{}", suggestion.to_yaml_block());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,213 @@
+use anyhow::Result;
+use std::collections::HashMap;
+
+use crate::publish::{GovbotConfig, LegislationItem};
+
+/// Turns a piece of text into a sparse term-weight vector so it can be compared against
+/// other text with cosine similarity.
+///
+/// A real embedder (e.g. a hosted embeddings API) can be plugged in here;
+/// `TfIdfEmbedder` is the default so tagging works fully offline and in tests.
+pub trait Embedder {
+    fn embed(&self, text: &str) -> HashMap<String, f64>;
+}
+
+/// Bag-of-words embedder with no external dependencies. Each term's weight is
+/// its raw count in the text; no inverse-document-frequency weighting is
+/// applied.
+///
+/// The "vector" is a sparse term -> weight map rather than a fixed-size dense
+/// array, which keeps comparisons simple and avoids needing a shared
+/// vocabulary up front.
+pub struct TfIdfEmbedder;
+
+impl Embedder for TfIdfEmbedder {
+    fn embed(&self, text: &str) -> HashMap<String, f64> {
+        let mut counts: HashMap<String, f64> = HashMap::new();
+        for token in tokenize(text) {
+            *counts.entry(token).or_insert(0.0) += 1.0;
+        }
+        counts
+    }
+}
+
+fn tokenize(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_string())
+        .collect()
+}
+
+/// Cosine similarity between two sparse term-weight vectors.
+fn cosine_similarity(a: &HashMap<String, f64>, b: &HashMap<String, f64>) -> f64 {
+    let (shorter, longer) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+
+    let dot: f64 = shorter
+        .iter()
+        .filter_map(|(term, weight)| longer.get(term).map(|other| weight * other))
+        .sum();
+
+    let norm_a: f64 = a.values().map(|w| w * w).sum::<f64>().sqrt();
+    let norm_b: f64 = b.values().map(|w| w * w).sum::<f64>().sqrt();
+
+    if norm_a == 0.0 || norm_b == 0.0 {
+        0.0
+    } else {
+        dot / (norm_a * norm_b)
+    }
+}
+
+// Only the offline fallback ships today; this match gives future embedder
+// backends (e.g. a hosted embeddings API) a place to land without changing
+// the `tagging:` schema.
+#[allow(clippy::match_single_binding)]
+fn embedder_for(config: &GovbotConfig) -> Box<dyn Embedder> {
+    match config.tagging.embedder.as_str() {
+        _ => Box::new(TfIdfEmbedder),
+    }
+}
+
+/// Score each item against every configured tag and assign every tag whose
+/// cosine similarity clears the configured threshold.
+///
+/// No-op when `tags:` is empty.
+pub fn tag_items(config: &GovbotConfig, items: &mut [LegislationItem]) -> Result<()> {
+    if config.tags.is_empty() {
+        return Ok(());
+    }
+
+    let embedder = embedder_for(config);
+    let threshold = config.tagging.threshold;
+
+    let tag_vectors: Vec<(&str, HashMap<String, f64>)> = config
+        .tags
+        .iter()
+        .map(|(name, tag)| {
+            let mut text = tag.description.clone();
+            for example in &tag.examples {
+                text.push(' ');
+                text.push_str(example);
+            }
+            (name.as_str(), embedder.embed(&text))
+        })
+        .collect();
+
+    for item in items.iter_mut() {
+        let item_text = format!("{} {}", item.title, item.summary);
+        let item_vector = embedder.embed(&item_text);
+
+        let mut assigned: Vec<String> = tag_vectors
+            .iter()
+            .filter_map(|(name, tag_vector)| {
+                let score = cosine_similarity(&item_vector, tag_vector);
+                (score >= threshold).then(|| name.to_string())
+            })
+            .collect();
+
+        assigned.sort();
+        item.tags = assigned;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::publish::{BuildConfig, TagDefinition, TaggingConfig, TimeoutsConfig};
+
+    fn config_with_tags(threshold: f64) -> GovbotConfig {
+        let mut tags = HashMap::new();
+        tags.insert(
+            "education".to_string(),
+            TagDefinition {
+                description: "Legislation related to schools and curriculum standards.".to_string(),
+                examples: vec!["Increases per-pupil funding for public schools".to_string()],
+            },
+        );
+        tags.insert(
+            "health".to_string(),
+            TagDefinition {
+                description: "Legislation related to hospitals and insurance coverage.".to_string(),
+                examples: vec!["Expands Medicaid eligibility for low-income families".to_string()],
+            },
+        );
+
+        GovbotConfig {
+            repos: vec!["all".into()],
+            tags,
+            build: BuildConfig {
+                base_url: "https://example.com".to_string(),
+                output_dir: "docs".to_string(),
+                output_file: "feed.xml".to_string(),
+                timeouts: TimeoutsConfig::default(),
+            },
+            tagging: TaggingConfig {
+                threshold,
+                ..TaggingConfig::default()
+            },
+            template: None,
+        }
+    }
+
+    #[test]
+    fn assigns_matching_tag_above_threshold() {
+        let config = config_with_tags(0.1);
+        let mut items = vec![LegislationItem {
+            title: "School funding increase".to_string(),
+            summary: "Increases per-pupil funding for public schools statewide.".to_string(),
+            url: "https://example.com/bill/1".to_string(),
+            tags: Vec::new(),
+            ..Default::default()
+        }];
+
+        tag_items(&config, &mut items).unwrap();
+
+        assert_eq!(items[0].tags, vec!["education".to_string()]);
+    }
+
+    #[test]
+    fn no_op_when_no_tags_defined() {
+        let config = GovbotConfig {
+            repos: vec!["all".into()],
+            tags: HashMap::new(),
+            build: BuildConfig {
+                base_url: "https://example.com".to_string(),
+                output_dir: "docs".to_string(),
+                output_file: "feed.xml".to_string(),
+                timeouts: TimeoutsConfig::default(),
+            },
+            tagging: TaggingConfig::default(),
+            template: None,
+        };
+        let mut items = vec![LegislationItem {
+            title: "Unrelated bill".to_string(),
+            summary: "Does something unrelated.".to_string(),
+            url: "https://example.com/bill/2".to_string(),
+            tags: Vec::new(),
+            ..Default::default()
+        }];
+
+        tag_items(&config, &mut items).unwrap();
+
+        assert!(items[0].tags.is_empty());
+    }
+
+    #[test]
+    fn assigns_multiple_tags_when_both_score_above_threshold() {
+        let config = config_with_tags(0.1);
+        let mut items = vec![LegislationItem {
+            title: "School health clinics".to_string(),
+            summary: "Funds public schools to open on-site health clinics for students."
+                .to_string(),
+            url: "https://example.com/bill/3".to_string(),
+            tags: Vec::new(),
+            ..Default::default()
+        }];
+
+        tag_items(&config, &mut items).unwrap();
+
+        assert_eq!(items[0].tags, vec!["education".to_string(), "health".to_string()]);
+    }
+}
@@ -0,0 +1,367 @@
+use anyhow::{bail, Result};
+
+use crate::publish::LegislationItem;
+
+/// Fields a template is allowed to reference, matching `LegislationItem`.
+const KNOWN_FIELDS: &[&str] = &["title", "summary", "url", "tags", "introduced_date", "state"];
+
+/// The feed-item template used when govbot.yml has no `template:` key.
+/// Renders the same markup `publish::render_feed` always has, so adding
+/// templating doesn't change existing output.
+pub const DEFAULT_TEMPLATE: &str = concat!(
+    "    <item>\n",
+    "      <title>{{title}}</title>\n",
+    "      <description>{{summary}}</description>\n",
+    "      <link>{{url}}</link>\n",
+    "{{#if tags}}",
+    "{{#each tags}}",
+    "      <category>{{tag}}</category>\n",
+    "{{/each}}",
+    "{{/if}}",
+    "    </item>\n",
+);
+
+#[derive(Debug, Clone)]
+enum Node {
+    Text(String),
+    Var { field: String, filters: Vec<Filter> },
+    If { field: String, body: Vec<Node> },
+    Each { field: String, body: Vec<Node> },
+}
+
+#[derive(Debug, Clone)]
+enum Filter {
+    Truncate(usize),
+    Join(String),
+    Date(String),
+}
+
+enum Token<'a> {
+    Text(&'a str),
+    Tag(&'a str),
+}
+
+/// A compiled feed-item template.
+///
+/// Compiling once up front (rather than re-parsing per item) and validating
+/// every referenced field against `LegislationItem` at compile time means a
+/// typo in govbot.yml's `template:` block fails fast at load, not partway
+/// through a publish run.
+#[derive(Debug)]
+pub struct Template {
+    nodes: Vec<Node>,
+}
+
+impl Template {
+    pub fn compile(source: &str) -> Result<Self> {
+        let tokens = tokenize(source);
+        let mut pos = 0;
+        let nodes = parse(&tokens, &mut pos)?;
+        if pos != tokens.len() {
+            bail!("unexpected {{{{/if}}}} or {{{{/each}}}} in template");
+        }
+        validate_fields(&nodes)?;
+        Ok(Template { nodes })
+    }
+
+    pub fn render(&self, item: &LegislationItem) -> String {
+        render_nodes(&self.nodes, item)
+    }
+}
+
+fn tokenize(source: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = source;
+    while let Some(start) = rest.find("{{") {
+        if start > 0 {
+            tokens.push(Token::Text(&rest[..start]));
+        }
+        let after = &rest[start + 2..];
+        match after.find("}}") {
+            Some(end) => {
+                tokens.push(Token::Tag(after[..end].trim()));
+                rest = &after[end + 2..];
+            }
+            None => {
+                tokens.push(Token::Text(&rest[start..]));
+                rest = "";
+                break;
+            }
+        }
+    }
+    if !rest.is_empty() {
+        tokens.push(Token::Text(rest));
+    }
+    tokens
+}
+
+fn parse(tokens: &[Token], pos: &mut usize) -> Result<Vec<Node>> {
+    let mut nodes = Vec::new();
+    while *pos < tokens.len() {
+        match &tokens[*pos] {
+            Token::Text(text) => {
+                nodes.push(Node::Text(text.to_string()));
+                *pos += 1;
+            }
+            Token::Tag(tag) if *tag == "/if" || *tag == "/each" => {
+                return Ok(nodes);
+            }
+            Token::Tag(tag) => {
+                if let Some(field) = tag.strip_prefix("#if ") {
+                    *pos += 1;
+                    let body = parse(tokens, pos)?;
+                    expect_closing(tokens, pos, "/if")?;
+                    nodes.push(Node::If {
+                        field: field.trim().to_string(),
+                        body,
+                    });
+                } else if let Some(field) = tag.strip_prefix("#each ") {
+                    *pos += 1;
+                    let body = parse(tokens, pos)?;
+                    expect_closing(tokens, pos, "/each")?;
+                    nodes.push(Node::Each {
+                        field: field.trim().to_string(),
+                        body,
+                    });
+                } else {
+                    let (field, filters) = parse_var(tag)?;
+                    nodes.push(Node::Var { field, filters });
+                    *pos += 1;
+                }
+            }
+        }
+    }
+    Ok(nodes)
+}
+
+fn expect_closing(tokens: &[Token], pos: &mut usize, expected: &str) -> Result<()> {
+    match tokens.get(*pos) {
+        Some(Token::Tag(tag)) if *tag == expected => {
+            *pos += 1;
+            Ok(())
+        }
+        _ => bail!("unterminated {{{{#{}}}}} block", &expected[1..]),
+    }
+}
+
+fn parse_var(tag: &str) -> Result<(String, Vec<Filter>)> {
+    let mut parts = tag.split('|').map(str::trim);
+    let field = match parts.next() {
+        Some(f) if !f.is_empty() => f.to_string(),
+        _ => bail!("empty template field reference"),
+    };
+
+    let mut filters = Vec::new();
+    for filter_str in parts {
+        let (name, arg) = filter_str.split_once(':').unwrap_or((filter_str, ""));
+        let arg = arg.trim().trim_matches('"');
+        match name.trim() {
+            "truncate" => {
+                let len: usize = arg
+                    .parse()
+                    .map_err(|_| anyhow::anyhow!("truncate filter needs a numeric length, got '{}'", arg))?;
+                filters.push(Filter::Truncate(len));
+            }
+            "join" => filters.push(Filter::Join(arg.to_string())),
+            "date" => filters.push(Filter::Date(arg.to_string())),
+            other => bail!("unknown template filter '{}'", other),
+        }
+    }
+
+    Ok((field, filters))
+}
+
+fn validate_fields(nodes: &[Node]) -> Result<()> {
+    for node in nodes {
+        match node {
+            Node::Text(_) => {}
+            Node::Var { field, .. } => check_field(field)?,
+            Node::If { field, body } | Node::Each { field, body } => {
+                check_field(field)?;
+                validate_fields(body)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check_field(field: &str) -> Result<()> {
+    // `tag` is the implicit loop variable inside `{{#each tags}}`, not a
+    // top-level item field.
+    if field == "tag" || KNOWN_FIELDS.contains(&field) {
+        Ok(())
+    } else {
+        bail!("unknown template field '{}'", field)
+    }
+}
+
+fn field_str(item: &LegislationItem, field: &str) -> String {
+    match field {
+        "title" => item.title.clone(),
+        "summary" => item.summary.clone(),
+        "url" => item.url.clone(),
+        "introduced_date" => item.introduced_date.clone().unwrap_or_default(),
+        "state" => item.state.clone().unwrap_or_default(),
+        "tags" => item.tags.join(", "),
+        _ => String::new(),
+    }
+}
+
+fn field_truthy(item: &LegislationItem, field: &str) -> bool {
+    match field {
+        "tags" => !item.tags.is_empty(),
+        "introduced_date" => item.introduced_date.is_some(),
+        "state" => item.state.is_some(),
+        _ => !field_str(item, field).is_empty(),
+    }
+}
+
+fn apply_filters(mut value: String, filters: &[Filter]) -> String {
+    for filter in filters {
+        value = match filter {
+            Filter::Truncate(len) => truncate(&value, *len),
+            Filter::Join(sep) => value.split(", ").collect::<Vec<_>>().join(sep),
+            Filter::Date(format) => format_date(&value, format),
+        };
+    }
+    value
+}
+
+fn truncate(s: &str, len: usize) -> String {
+    if s.chars().count() <= len {
+        s.to_string()
+    } else {
+        let truncated: String = s.chars().take(len).collect();
+        format!("{}...", truncated)
+    }
+}
+
+/// Reformat a `YYYY-MM-DD` date string using a small subset of strftime-style
+/// directives (`%Y`, `%m`, `%d`). Anything else passes through unchanged.
+fn format_date(date: &str, format: &str) -> String {
+    let parts: Vec<&str> = date.splitn(3, '-').collect();
+    let [year, month, day] = match parts[..] {
+        [y, m, d] => [y, m, d],
+        _ => return date.to_string(),
+    };
+
+    format
+        .replace("%Y", year)
+        .replace("%m", month)
+        .replace("%d", day)
+}
+
+/// Escape the characters that are special in XML text content (and in
+/// double-quoted attribute values, though this template format never
+/// generates those) so bill titles/summaries/urls can't break the feed's
+/// markup or smuggle in extra elements.
+fn escape_xml(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn render_nodes(nodes: &[Node], item: &LegislationItem) -> String {
+    let mut out = String::new();
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(text),
+            Node::Var { field, filters } => {
+                out.push_str(&escape_xml(&apply_filters(field_str(item, field), filters)));
+            }
+            Node::If { field, body } => {
+                if field_truthy(item, field) {
+                    out.push_str(&render_nodes(body, item));
+                }
+            }
+            Node::Each { field, body } => {
+                if field == "tags" {
+                    for tag in &item.tags {
+                        out.push_str(&render_each_body(body, item, tag));
+                    }
+                }
+            }
+        }
+    }
+    out
+}
+
+/// `{{#each tags}}` bodies may reference `{{tag}}` for the current element;
+/// everything else renders as usual.
+fn render_each_body(nodes: &[Node], item: &LegislationItem, tag: &str) -> String {
+    let mut out = String::new();
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(text),
+            Node::Var { field, filters } if field == "tag" => {
+                out.push_str(&escape_xml(&apply_filters(tag.to_string(), filters)));
+            }
+            Node::Var { field, filters } => {
+                out.push_str(&escape_xml(&apply_filters(field_str(item, field), filters)));
+            }
+            Node::If { field, body } => {
+                if field_truthy(item, field) {
+                    out.push_str(&render_each_body(body, item, tag));
+                }
+            }
+            Node::Each { .. } => {
+                // Nested `{{#each}}` is not supported; render nothing rather
+                // than silently misinterpreting the loop variable.
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_item() -> LegislationItem {
+        LegislationItem {
+            title: "School funding increase".to_string(),
+            summary: "Increases per-pupil funding for public schools statewide.".to_string(),
+            url: "https://example.com/bill/1".to_string(),
+            tags: vec!["education".to_string()],
+            introduced_date: Some("2024-03-05".to_string()),
+            state: Some("il".to_string()),
+        }
+    }
+
+    #[test]
+    fn renders_default_template_unchanged() {
+        let template = Template::compile(DEFAULT_TEMPLATE).unwrap();
+        let out = template.render(&sample_item());
+
+        assert!(out.contains("<title>School funding increase</title>"));
+        assert!(out.contains("<category>education</category>"));
+    }
+
+    #[test]
+    fn conditional_block_is_skipped_without_tags() {
+        let template = Template::compile(DEFAULT_TEMPLATE).unwrap();
+        let mut item = sample_item();
+        item.tags.clear();
+        let out = template.render(&item);
+
+        assert!(!out.contains("<category>"));
+    }
+
+    #[test]
+    fn truncate_and_date_filters_apply() {
+        let template = Template::compile(
+            "{{summary|truncate:10}} ({{introduced_date|date:\"%m/%d/%Y\"}})",
+        )
+        .unwrap();
+        let out = template.render(&sample_item());
+
+        assert_eq!(out, "Increases ... (03/05/2024)");
+    }
+
+    #[test]
+    fn rejects_unknown_field() {
+        let err = Template::compile("{{sponsor}}").unwrap_err();
+        assert!(err.to_string().contains("unknown template field 'sponsor'"));
+    }
+}
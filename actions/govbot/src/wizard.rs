@@ -3,12 +3,97 @@ use dialoguer::{Input, Select};
 use std::fs;
 use std::path::Path;
 
+use crate::messages::Catalog;
+
 /// Represents the user's choices during the wizard.
 /// Used both by the interactive wizard and by tests to simulate different paths.
+#[derive(Default)]
 pub struct WizardChoices {
     pub repos: Vec<String>,
     pub include_example_tag: bool,
     pub base_url: String,
+    pub profile: Profile,
+}
+
+/// A setup profile offered as the first wizard step. Each profile seeds
+/// sensible defaults into `WizardChoices` so users who match one of these
+/// shapes can skip the rest of the interrogation, rather than toggling every
+/// choice by hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Profile {
+    Journalist,
+    Advocacy,
+    Researcher,
+    Developer,
+    #[default]
+    Custom,
+}
+
+impl Profile {
+    /// Every profile, in the order they're offered to the user.
+    pub fn all() -> impl Iterator<Item = Profile> {
+        [
+            Profile::Journalist,
+            Profile::Advocacy,
+            Profile::Researcher,
+            Profile::Developer,
+            Profile::Custom,
+        ]
+        .into_iter()
+    }
+
+    /// The lowercase name used on the command line, e.g. `--profile researcher`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Profile::Journalist => "journalist",
+            Profile::Advocacy => "advocacy",
+            Profile::Researcher => "researcher",
+            Profile::Developer => "developer",
+            Profile::Custom => "custom",
+        }
+    }
+
+    /// Parse a profile from its command-line name, case-insensitively.
+    pub fn from_name(name: &str) -> Option<Profile> {
+        Profile::all().find(|p| p.name().eq_ignore_ascii_case(name))
+    }
+
+    /// A one-line description shown in the profile selection menu.
+    pub fn purpose(&self) -> &'static str {
+        match self {
+            Profile::Journalist => {
+                "Track a handful of high-salience states with example tags for common beats"
+            }
+            Profile::Advocacy => "Track every jurisdiction with example tags to start from",
+            Profile::Researcher => "Track every jurisdiction with no preset tags",
+            Profile::Developer => "Track every jurisdiction with a CI workflow tuned for frequent runs",
+            Profile::Custom => "Walk through every choice yourself",
+        }
+    }
+
+    /// List every profile with its purpose, for `--help`/non-interactive output.
+    pub fn all_for_help() -> String {
+        Profile::all()
+            .map(|p| format!("  {:<10} {}", p.name(), p.purpose()))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Seed sensible `WizardChoices` defaults for this profile.
+    pub fn seed_choices(&self) -> WizardChoices {
+        let repos = match self {
+            Profile::Journalist => vec!["il".to_string(), "ca".to_string(), "ny".to_string(), "tx".to_string(), "fl".to_string()],
+            _ => vec!["all".to_string()],
+        };
+        let include_example_tag = matches!(self, Profile::Journalist | Profile::Advocacy);
+
+        WizardChoices {
+            repos,
+            include_example_tag,
+            base_url: "https://example.com".to_string(),
+            profile: *self,
+        }
+    }
 }
 
 /// Captures the full wizard session output: what the user sees at each step,
@@ -23,70 +108,91 @@ pub struct WizardSession {
 }
 
 impl WizardSession {
-    /// Render a complete wizard session from a set of choices.
-    /// This is deterministic and requires no interactive input.
+    /// Render a complete wizard session from a set of choices, using the
+    /// process-wide active locale. This is deterministic and requires no
+    /// interactive input.
     pub fn from_choices(choices: &WizardChoices) -> Self {
+        Self::from_choices_with_catalog(choices, crate::messages::active())
+    }
+
+    /// Same as [`from_choices`](Self::from_choices), but renders every piece
+    /// of wizard text (the display preview *and* the generated files) from
+    /// an explicit `Catalog` rather than the process-wide active one, so
+    /// tests can snapshot the full session in a locale other than whichever
+    /// one happens to be active.
+    pub fn from_choices_with_catalog(choices: &WizardChoices, msg: &Catalog) -> Self {
         let mut display = String::new();
 
         // Welcome
-        display.push_str("Welcome to govbot! Let's set up your project.\n\n");
+        display.push_str(msg.get("welcome"));
+        display.push_str("\n\n");
+
+        // Step 0: Profile
+        display.push_str(&format!("? {}\n", msg.get("profile-prompt")));
+        for profile in Profile::all() {
+            let marker = if profile == choices.profile { "> " } else { "  " };
+            display.push_str(&format!("{}{} — {}\n", marker, profile.name(), profile.purpose()));
+        }
+        display.push('\n');
 
         // Step 1: Sources
-        display.push_str("? What data sources do you want to track?\n");
+        display.push_str(&format!("? {}\n", msg.get("sources-prompt")));
         if choices.repos == ["all"] {
-            display.push_str("> All states (47 jurisdictions)\n");
-            display.push_str("  Select specific states\n");
+            display.push_str(&format!("> {}\n", msg.get("sources-all-label")));
+            display.push_str(&format!("  {}\n", msg.get("sources-specific-label")));
         } else {
-            display.push_str("  All states (47 jurisdictions)\n");
-            display.push_str("> Select specific states\n");
+            display.push_str(&format!("  {}\n", msg.get("sources-all-label")));
+            display.push_str(&format!("> {}\n", msg.get("sources-specific-label")));
+            display.push('\n');
+            display.push_str(msg.get("sources-available-header"));
             display.push('\n');
-            display.push_str("Available states/jurisdictions:\n");
             let all_locales = crate::locale::WorkingLocale::all();
             let locale_strs: Vec<String> = all_locales.iter().map(|l| l.as_str().to_string()).collect();
             for chunk in locale_strs.chunks(10) {
                 display.push_str(&format!("  {}\n", chunk.join(", ")));
             }
             display.push('\n');
-            display.push_str(&format!("? Enter state codes separated by spaces: {}\n", choices.repos.join(" ")));
+            display.push_str(&format!("? {}: {}\n", msg.get("sources-input-label"), choices.repos.join(" ")));
         }
         display.push('\n');
 
         // Step 2: Tags
-        display.push_str("Tags let govbot categorize legislation by topics you care about.\n");
-        display.push_str("Here's an example tag definition:\n\n");
-        display.push_str("  education:\n");
-        display.push_str("    description: |\n");
-        display.push_str("      Legislation related to schools, education funding,\n");
-        display.push_str("      curriculum standards, and educational policy.\n");
-        display.push_str("    examples:\n");
-        display.push_str("      - \"Increases per-pupil funding for public schools\"\n");
-        display.push_str("      - \"Mandates comprehensive sex education curriculum\"\n\n");
-
-        display.push_str("? How would you like to set up tags?\n");
+        display.push_str(msg.get("tags-intro-1"));
+        display.push('\n');
+        display.push_str(msg.get("tags-intro-2"));
+        display.push_str("\n\n");
+        display.push_str(&example_tag_block(msg));
+        display.push('\n');
+
+        display.push_str(&format!("? {}\n", msg.get("tags-prompt")));
         if choices.include_example_tag {
-            display.push_str("> Use the example \"education\" tag to start\n");
-            display.push_str("  I'll create my own tags later\n");
+            display.push_str(&format!("> {}\n", msg.get("tags-option-use-example")));
+            display.push_str(&format!("  {}\n", msg.get("tags-option-own")));
         } else {
-            display.push_str("  Use the example \"education\" tag to start\n");
-            display.push_str("> I'll create my own tags later\n");
+            display.push_str(&format!("  {}\n", msg.get("tags-option-use-example")));
+            display.push_str(&format!("> {}\n", msg.get("tags-option-own")));
             display.push('\n');
-            display.push_str(&ai_prompt_template());
+            display.push_str(&ai_prompt_template_with(msg));
         }
         display.push('\n');
 
         // Step 3: Publishing
-        display.push_str("Publishing is configured for RSS feeds by default.\n");
-        display.push_str("Your feeds will be generated in the \"docs\" directory.\n\n");
-        display.push_str(&format!("? Base URL for your feeds: {}\n\n", choices.base_url));
+        display.push_str(msg.get("publishing-intro-1"));
+        display.push('\n');
+        display.push_str(msg.get("publishing-intro-2"));
+        display.push_str("\n\n");
+        display.push_str(&format!("? {}: {}\n\n", msg.get("publishing-prompt-label"), choices.base_url));
 
         // Summary
-        display.push_str("  ✓ Created govbot.yml\n");
-        display.push_str("  ✓ Created .gitignore with .govbot\n");
-        display.push_str("  ✓ Created .github/workflows/build.yml\n\n");
-        display.push_str("Setup complete! Run 'govbot' again to start the pipeline.\n");
+        display.push_str(&format!("  ✓ {}\n", msg.get("summary-created-yml")));
+        display.push_str(&format!("  ✓ {}\n", msg.get("summary-created-gitignore")));
+        display.push_str(&format!("  ✓ {}\n\n", msg.get("summary-created-workflow")));
+        display.push_str(msg.get("setup-complete-wizard"));
+        display.push('\n');
 
-        let govbot_yml = generate_govbot_yml(&choices.repos, choices.include_example_tag, &choices.base_url);
-        let workflow_yml = github_workflow_content().to_string();
+        let govbot_yml =
+            generate_govbot_yml_with(&choices.repos, choices.include_example_tag, &choices.base_url, msg);
+        let workflow_yml = github_workflow_content(choices.profile);
 
         WizardSession {
             display,
@@ -100,13 +206,13 @@ impl WizardSession {
         // Write govbot.yml
         let config_path = dir.join("govbot.yml");
         fs::write(&config_path, &self.govbot_yml)?;
-        eprintln!("  ✓ Created govbot.yml");
+        eprintln!("  ✓ {}", crate::messages::active().get("summary-created-yml"));
 
         // Write .gitignore
         write_gitignore(dir)?;
 
         // Write GitHub Actions workflow
-        write_github_workflow(dir)?;
+        write_github_workflow(dir, &self.workflow_yml)?;
 
         Ok(())
     }
@@ -125,17 +231,52 @@ impl WizardSession {
     }
 }
 
-/// The AI prompt template shown when users choose to create their own tags.
+/// Render the example `education` tag block shown both as a preview during
+/// the wizard's tags step (`from_choices`, `prompt_tags`) and written into
+/// govbot.yml itself (`generate_govbot_yml`) when the user opts into it, so
+/// the preview always matches what ends up on disk.
+fn example_tag_block(msg: &Catalog) -> String {
+    let mut out = String::new();
+    out.push_str("  education:\n");
+    out.push_str("    description: |\n");
+    out.push_str(&format!("      {}\n", msg.get("yml-example-tag-description-intro")));
+    for i in 1..=12 {
+        out.push_str(&format!("      - {}\n", msg.get(&format!("yml-example-tag-bullet-{}", i))));
+    }
+    out.push_str("    examples:\n");
+    for i in 1..=3 {
+        out.push_str(&format!(
+            "      - \"{}\"\n",
+            msg.get(&format!("yml-example-tag-example-{}", i))
+        ));
+    }
+    out
+}
+
+/// The AI prompt template shown when users choose to create their own tags,
+/// using the process-wide active locale.
 pub fn ai_prompt_template() -> String {
+    ai_prompt_template_with(crate::messages::active())
+}
+
+/// Same as [`ai_prompt_template`], but renders from an explicit `Catalog`.
+fn ai_prompt_template_with(msg: &Catalog) -> String {
     let mut s = String::new();
-    s.push_str("To create a tag, copy this prompt into your preferred AI tool:\n\n");
+    s.push_str(msg.get("ai-prompt-intro"));
+    s.push_str("\n\n");
     s.push_str("---\n");
-    s.push_str("Create a govbot tag definition in YAML for tracking [YOUR TOPIC] legislation.\n");
-    s.push_str("The tag should have:\n");
-    s.push_str("- A description (multiline, covering subtopics)\n");
-    s.push_str("- 2-3 example bill descriptions that would match\n");
-    s.push_str("- Optional: include_keywords and exclude_keywords lists\n\n");
-    s.push_str("Format:\n");
+    s.push_str(msg.get("ai-prompt-create"));
+    s.push('\n');
+    s.push_str(msg.get("ai-prompt-should-have"));
+    s.push('\n');
+    s.push_str(msg.get("ai-prompt-bullet-desc"));
+    s.push('\n');
+    s.push_str(msg.get("ai-prompt-bullet-examples"));
+    s.push('\n');
+    s.push_str(msg.get("ai-prompt-bullet-optional"));
+    s.push_str("\n\n");
+    s.push_str(msg.get("ai-prompt-format"));
+    s.push('\n');
     s.push_str("  tag_name:\n");
     s.push_str("    description: |\n");
     s.push_str("      ...\n");
@@ -146,23 +287,23 @@ pub fn ai_prompt_template() -> String {
     s.push_str("    exclude_keywords:\n");
     s.push_str("      - keyword1\n");
     s.push_str("---\n\n");
-    s.push_str("Paste the result into your govbot.yml under the 'tags:' section.\n");
+    s.push_str(msg.get("ai-prompt-paste"));
+    s.push('\n');
     s
 }
 
 /// Generate default govbot.yml and supporting files without interactive prompts.
-/// Used when `govbot init` is run in a non-interactive terminal.
-pub fn write_default_files(dir: &Path) -> Result<()> {
-    let choices = WizardChoices {
-        repos: vec!["all".to_string()],
-        include_example_tag: true,
-        base_url: "https://example.com".to_string(),
-    };
+/// Used when `govbot init` is run in a non-interactive terminal, or via
+/// `govbot init --profile <name>`. Falls back to the Researcher profile
+/// (track everything, no preset tags) for an unrecognized or missing name.
+pub fn write_default_files(dir: &Path, profile: &str) -> Result<()> {
+    let profile = Profile::from_name(profile).unwrap_or(Profile::Researcher);
+    let choices = profile.seed_choices();
     let session = WizardSession::from_choices(&choices);
     session.write_files(dir)?;
 
     eprintln!();
-    eprintln!("Setup complete! Edit govbot.yml to customize, then run 'govbot' to start.");
+    eprintln!("{}", crate::messages::active().get("setup-complete-init"));
     eprintln!();
 
     Ok(())
@@ -170,51 +311,79 @@ pub fn write_default_files(dir: &Path) -> Result<()> {
 
 /// Run the interactive setup wizard to create govbot.yml and supporting files.
 pub fn run_wizard() -> Result<()> {
+    let msg = crate::messages::active();
+
     // Check if stdin is a terminal - wizard requires interactive input
     if !std::io::IsTerminal::is_terminal(&std::io::stdin()) {
-        eprintln!("No govbot.yml found in current directory.");
-        eprintln!("Run 'govbot' in an interactive terminal to launch the setup wizard.");
+        eprintln!("{}", msg.get("no-terminal-1"));
+        eprintln!("{}", msg.get("no-terminal-2"));
         return Ok(());
     }
 
     eprintln!();
-    eprintln!("Welcome to govbot! Let's set up your project.");
+    eprintln!("{}", msg.get("welcome"));
     eprintln!();
 
-    // Step 1: Sources
-    let repos = prompt_sources()?;
+    // Step 0: Profile
+    let profile = prompt_profile()?;
+
+    let choices = if profile == Profile::Custom {
+        // Step 1: Sources
+        let repos = prompt_sources()?;
+
+        // Step 2: Tags
+        let include_example_tag = prompt_tags()?;
 
-    // Step 2: Tags
-    let include_example_tag = prompt_tags()?;
+        // Step 3: Publishing info
+        let base_url = prompt_publishing()?;
 
-    // Step 3: Publishing info
-    let base_url = prompt_publishing()?;
+        WizardChoices {
+            repos,
+            include_example_tag,
+            base_url,
+            profile,
+        }
+    } else {
+        eprintln!();
+        eprintln!("{}", msg.get_fmt("profile-using-defaults", &[("name", profile.name())]));
+        profile.seed_choices()
+    };
 
     // Generate and write files
     let cwd = std::env::current_dir()?;
-    let choices = WizardChoices {
-        repos,
-        include_example_tag,
-        base_url,
-    };
     let session = WizardSession::from_choices(&choices);
     session.write_files(&cwd)?;
 
     eprintln!();
-    eprintln!("Setup complete! Run 'govbot' again to start the pipeline.");
+    eprintln!("{}", msg.get("setup-complete-wizard"));
     eprintln!();
 
     Ok(())
 }
 
+fn prompt_profile() -> Result<Profile> {
+    let profiles: Vec<Profile> = Profile::all().collect();
+    let items: Vec<String> = profiles
+        .iter()
+        .map(|p| format!("{} — {}", p.name(), p.purpose()))
+        .collect();
+    let custom_index = profiles.iter().position(|p| *p == Profile::Custom).unwrap_or(0);
+
+    let selection = Select::new()
+        .with_prompt(crate::messages::active().get("profile-prompt"))
+        .items(&items)
+        .default(custom_index)
+        .interact()?;
+
+    Ok(profiles[selection])
+}
+
 fn prompt_sources() -> Result<Vec<String>> {
-    let options = vec![
-        "All states (47 jurisdictions)",
-        "Select specific states",
-    ];
+    let msg = crate::messages::active();
+    let options = vec![msg.get("sources-all-label"), msg.get("sources-specific-label")];
 
     let selection = Select::new()
-        .with_prompt("What data sources do you want to track?")
+        .with_prompt(msg.get("sources-prompt"))
         .items(&options)
         .default(0)
         .interact()?;
@@ -228,14 +397,14 @@ fn prompt_sources() -> Result<Vec<String>> {
     let locale_strs: Vec<String> = all_locales.iter().map(|l| l.as_str().to_string()).collect();
 
     eprintln!();
-    eprintln!("Available states/jurisdictions:");
+    eprintln!("{}", msg.get("sources-available-header"));
     for chunk in locale_strs.chunks(10) {
         eprintln!("  {}", chunk.join(", "));
     }
     eprintln!();
 
     let input: String = Input::new()
-        .with_prompt("Enter state codes separated by spaces (e.g., il ca ny)")
+        .with_prompt(msg.get("sources-input-prompt"))
         .interact_text()?;
 
     let repos: Vec<String> = input
@@ -252,26 +421,20 @@ fn prompt_sources() -> Result<Vec<String>> {
 }
 
 fn prompt_tags() -> Result<bool> {
+    let msg = crate::messages::active();
     eprintln!();
-    eprintln!("Tags let govbot categorize legislation by topics you care about.");
-    eprintln!("Here's an example tag definition:");
+    eprintln!("{}", msg.get("tags-intro-1"));
+    eprintln!("{}", msg.get("tags-intro-2"));
     eprintln!();
-    eprintln!("  education:");
-    eprintln!("    description: |");
-    eprintln!("      Legislation related to schools, education funding,");
-    eprintln!("      curriculum standards, and educational policy.");
-    eprintln!("    examples:");
-    eprintln!("      - \"Increases per-pupil funding for public schools\"");
-    eprintln!("      - \"Mandates comprehensive sex education curriculum\"");
+    for line in example_tag_block(msg).lines() {
+        eprintln!("{}", line);
+    }
     eprintln!();
 
-    let options = vec![
-        "Use the example \"education\" tag to start",
-        "I'll create my own tags later",
-    ];
+    let options = vec![msg.get("tags-option-use-example"), msg.get("tags-option-own")];
 
     let selection = Select::new()
-        .with_prompt("How would you like to set up tags?")
+        .with_prompt(msg.get("tags-prompt"))
         .items(&options)
         .default(0)
         .interact()?;
@@ -288,27 +451,45 @@ fn prompt_tags() -> Result<bool> {
 }
 
 fn prompt_publishing() -> Result<String> {
+    let msg = crate::messages::active();
     eprintln!();
-    eprintln!("Publishing is configured for RSS feeds by default.");
-    eprintln!("Your feeds will be generated in the \"docs\" directory.");
+    eprintln!("{}", msg.get("publishing-intro-1"));
+    eprintln!("{}", msg.get("publishing-intro-2"));
     eprintln!();
 
     let base_url: String = Input::new()
-        .with_prompt("Base URL for your feeds (e.g., https://username.github.io/repo-name)")
+        .with_prompt(msg.get("publishing-prompt"))
         .default("https://example.com".to_string())
         .interact_text()?;
 
     Ok(base_url)
 }
 
-/// Generate govbot.yml content from wizard answers.
-/// This is a pure function for easy testing.
+/// The canonical `govbot.yml` JSON Schema URL, used for both the `$schema:`
+/// key and the header comment pointing editors at it.
+const SCHEMA_URL: &str = "https://raw.githubusercontent.com/windy-civi/toolkit/main/schemas/govbot.schema.json";
+
+/// Generate govbot.yml content from wizard answers, using the process-wide
+/// active locale. This is a pure function for easy testing.
 pub fn generate_govbot_yml(repos: &[String], include_example_tag: bool, base_url: &str) -> String {
+    generate_govbot_yml_with(repos, include_example_tag, base_url, crate::messages::active())
+}
+
+/// Same as [`generate_govbot_yml`], but renders from an explicit `Catalog`.
+fn generate_govbot_yml_with(
+    repos: &[String],
+    include_example_tag: bool,
+    base_url: &str,
+    msg: &Catalog,
+) -> String {
     let mut yml = String::new();
 
-    yml.push_str("# Govbot Configuration\n");
-    yml.push_str("# Schema: https://raw.githubusercontent.com/windy-civi/toolkit/main/schemas/govbot.schema.json\n");
-    yml.push_str("$schema: https://raw.githubusercontent.com/windy-civi/toolkit/main/schemas/govbot.schema.json\n\n");
+    yml.push_str(&format!("# {}\n", msg.get("yml-header-title")));
+    yml.push_str(&format!(
+        "# {}\n",
+        msg.get_fmt("yml-header-schema-comment", &[("url", SCHEMA_URL)])
+    ));
+    yml.push_str(&format!("$schema: {}\n\n", SCHEMA_URL));
 
     // Repos section
     yml.push_str("repos:\n");
@@ -320,27 +501,9 @@ pub fn generate_govbot_yml(repos: &[String], include_example_tag: bool, base_url
     // Tags section
     yml.push_str("tags:\n");
     if include_example_tag {
-        yml.push_str("  education:\n");
-        yml.push_str("    description: |\n");
-        yml.push_str("      Legislation related to schools, education funding, curriculum standards, and educational policy, including:\n");
-        yml.push_str("      - K-12 public school funding, budgets, and resource allocation\n");
-        yml.push_str("      - Curriculum standards, content requirements, and academic programs\n");
-        yml.push_str("      - Teacher certification, training, professional development, and compensation\n");
-        yml.push_str("      - Higher education policy, tuition, financial aid, and student loans\n");
-        yml.push_str("      - Charter schools, school choice, vouchers, and alternative education models\n");
-        yml.push_str("      - Special education services, accommodations, and individualized education plans\n");
-        yml.push_str("      - School safety, security measures, and student discipline policies\n");
-        yml.push_str("      - Early childhood education, pre-K programs, and childcare\n");
-        yml.push_str("      - Standardized testing, assessments, and accountability measures\n");
-        yml.push_str("      - School district governance, administration, and oversight\n");
-        yml.push_str("      - Educational technology, digital learning, and online education\n");
-        yml.push_str("      - Career and technical education, vocational training, and workforce development\n");
-        yml.push_str("    examples:\n");
-        yml.push_str("      - \"Increases per-pupil funding for public schools and establishes minimum teacher salary requirements\"\n");
-        yml.push_str("      - \"Mandates comprehensive sex education curriculum in all public schools\"\n");
-        yml.push_str("      - \"Expands eligibility for state financial aid programs to include part-time students\"\n");
+        yml.push_str(&example_tag_block(msg));
     } else {
-        yml.push_str("  # Add your tags here. Example:\n");
+        yml.push_str(&format!("  # {}\n", msg.get("yml-tags-placeholder-comment")));
         yml.push_str("  # my_topic:\n");
         yml.push_str("  #   description: |\n");
         yml.push_str("  #     Legislation related to ...\n");
@@ -355,37 +518,70 @@ pub fn generate_govbot_yml(repos: &[String], include_example_tag: bool, base_url
     yml.push_str(&format!("  base_url: \"{}\"\n", base_url));
     yml.push_str("  output_dir: \"docs\"\n");
     yml.push_str("  output_file: \"feed.xml\"\n");
+    yml.push_str(&format!("  # {}\n", msg.get("yml-timeouts-comment-1")));
+    yml.push_str(&format!("  # {}\n", msg.get("yml-timeouts-comment-2")));
+    yml.push_str("  # timeouts:\n");
+    yml.push_str("  #   clone_secs: 300\n");
+    yml.push_str("  #   retries: 3\n");
+    yml.push('\n');
+
+    // Tagging section
+    yml.push_str("tagging:\n");
+    yml.push_str(&format!("  # {}\n", msg.get("yml-tagging-threshold-comment")));
+    yml.push_str("  threshold: 0.2\n");
+    yml.push_str(&format!("  # {}\n", msg.get("yml-tagging-embedder-comment")));
+    yml.push_str("  embedder: \"tfidf\"\n");
+    yml.push('\n');
+
+    // Template section
+    yml.push_str(&format!("# {}\n", msg.get("yml-template-comment-1")));
+    yml.push_str(&format!("# {}\n", msg.get("yml-template-comment-2")));
+    yml.push_str("# template: |\n");
+    yml.push_str("#   <item>\n");
+    yml.push_str("#     <title>{{title}}</title>\n");
+    yml.push_str("#     <description>{{summary|truncate:280}}</description>\n");
+    yml.push_str("#     <link>{{url}}</link>\n");
+    yml.push_str("#   </item>\n");
 
     yml
 }
 
 /// Write .gitignore with .govbot entry
 pub fn write_gitignore(cwd: &Path) -> Result<()> {
+    let msg = crate::messages::active();
     let gitignore_path = cwd.join(".gitignore");
     let gitignore_entry = ".govbot\n";
 
     if gitignore_path.exists() {
         let mut content = fs::read_to_string(&gitignore_path)?;
         if content.contains(".govbot") {
-            eprintln!("  ✓ .gitignore already contains .govbot");
+            eprintln!("  ✓ {}", msg.get("gitignore-already"));
         } else {
             if !content.ends_with('\n') {
                 content.push('\n');
             }
             content.push_str(gitignore_entry);
             fs::write(&gitignore_path, content)?;
-            eprintln!("  ✓ Updated .gitignore to include .govbot");
+            eprintln!("  ✓ {}", msg.get("gitignore-updated"));
         }
     } else {
         fs::write(&gitignore_path, gitignore_entry)?;
-        eprintln!("  ✓ Created .gitignore with .govbot");
+        eprintln!("  ✓ {}", msg.get("gitignore-created"));
     }
 
     Ok(())
 }
 
-fn github_workflow_content() -> &'static str {
-    r#"# Run Govbot
+fn github_workflow_content(profile: Profile) -> String {
+    // The Developer profile runs CI far more often, since it's meant for
+    // people actively iterating on tags/templates and wanting quick feedback.
+    let cron = match profile {
+        Profile::Developer => "*/15 * * * *",
+        _ => "0 0 * * *",
+    };
+
+    format!(
+        r#"# Run Govbot
 # Runs govbot to clone repos, tag bills, and build RSS feeds and HTML index.
 
 name: Build Govbot
@@ -396,7 +592,7 @@ on:
       - main
       - master
   schedule:
-    - cron: '0 0 * * *'
+    - cron: '{cron}'
   workflow_dispatch:
     inputs:
       tags:
@@ -419,19 +615,20 @@ jobs:
       - name: Run Govbot
         uses: windy-civi/toolkit/actions/govbot@main
         with:
-          tags: ${{ inputs.tags }}
-          limit: ${{ inputs.limit }}
+          tags: ${{{{ inputs.tags }}}}
+          limit: ${{{{ inputs.limit }}}}
 "#
+    )
 }
 
 /// Write GitHub Actions workflow file
-pub fn write_github_workflow(cwd: &Path) -> Result<()> {
+pub fn write_github_workflow(cwd: &Path, content: &str) -> Result<()> {
     let workflows_dir = cwd.join(".github").join("workflows");
     fs::create_dir_all(&workflows_dir)?;
 
     let workflow_path = workflows_dir.join("build.yml");
-    fs::write(&workflow_path, github_workflow_content())?;
-    eprintln!("  ✓ Created .github/workflows/build.yml");
+    fs::write(&workflow_path, content)?;
+    eprintln!("  ✓ {}", crate::messages::active().get("workflow-created"));
 
     Ok(())
 }
@@ -0,0 +1,43 @@
+use govbot::messages::load;
+
+#[test]
+fn falls_back_to_english_for_an_unknown_locale() {
+    let catalog = load("fr");
+    assert_eq!(catalog.get("welcome"), "Welcome to govbot! Let's set up your project.");
+}
+
+#[test]
+fn spanish_overlay_translates_known_ids() {
+    let catalog = load("es");
+    assert_eq!(catalog.get("welcome"), "¡Bienvenido a govbot! Configuremos tu proyecto.");
+}
+
+#[test]
+fn spanish_overlay_falls_back_to_english_for_ids_it_does_not_translate() {
+    // es.ftl doesn't override yml-template-comment-1, so it should still
+    // resolve to the English baseline rather than an empty string.
+    let en = load("en");
+    let es = load("es");
+    assert_eq!(es.get("yml-template-comment-1"), en.get("yml-template-comment-1"));
+    assert_ne!(es.get("welcome"), en.get("welcome"));
+}
+
+#[test]
+fn locale_subtags_and_unknown_ids_are_handled() {
+    // "es-MX.UTF-8"-style LANG values resolve to the base "es" subtag.
+    let catalog = load("es_MX.UTF-8");
+    assert_eq!(catalog.get("welcome"), "¡Bienvenido a govbot! Configuremos tu proyecto.");
+
+    // A totally unknown id returns itself rather than panicking.
+    assert_eq!(catalog.get("not-a-real-id"), "not-a-real-id");
+}
+
+#[test]
+fn get_fmt_substitutes_placeholders() {
+    let catalog = load("en");
+    let rendered = catalog.get_fmt("profile-using-defaults", &[("name", "researcher")]);
+    assert_eq!(
+        rendered,
+        "Using the \"researcher\" profile defaults. Edit govbot.yml afterwards to customize."
+    );
+}
@@ -0,0 +1,52 @@
+use govbot::publish::{get_repos_from_config, load_config};
+use govbot::wizard::{write_default_files, Profile};
+
+#[test]
+fn researcher_profile_tracks_everything_with_no_preset_tags() {
+    let choices = Profile::Researcher.seed_choices();
+    assert_eq!(choices.repos, vec!["all".to_string()]);
+    assert!(!choices.include_example_tag);
+}
+
+#[test]
+fn journalist_profile_seeds_a_handful_of_states_with_example_tag() {
+    let choices = Profile::Journalist.seed_choices();
+    assert!(choices.repos.len() > 1 && choices.repos.len() < 10);
+    assert!(choices.include_example_tag);
+}
+
+#[test]
+fn from_name_is_case_insensitive_and_rejects_unknown_names() {
+    assert_eq!(Profile::from_name("Developer"), Some(Profile::Developer));
+    assert_eq!(Profile::from_name("developer"), Some(Profile::Developer));
+    assert_eq!(Profile::from_name("not-a-profile"), None);
+}
+
+#[test]
+fn all_for_help_lists_every_profile() {
+    let help = Profile::all_for_help();
+    for profile in Profile::all() {
+        assert!(help.contains(profile.name()), "help text missing {}", profile.name());
+    }
+}
+
+#[test]
+fn write_default_files_with_profile_name_uses_that_profiles_defaults() {
+    let dir = tempfile::tempdir().unwrap();
+    write_default_files(dir.path(), "developer").unwrap();
+
+    let config = load_config(&dir.path().join("govbot.yml")).unwrap();
+    assert_eq!(get_repos_from_config(&config), vec!["all".to_string()]);
+
+    let workflow = std::fs::read_to_string(dir.path().join(".github/workflows/build.yml")).unwrap();
+    assert!(workflow.contains("*/15 * * * *"), "developer profile should use a frequent CI schedule");
+}
+
+#[test]
+fn write_default_files_falls_back_to_researcher_for_unknown_profile() {
+    let dir = tempfile::tempdir().unwrap();
+    write_default_files(dir.path(), "not-a-real-profile").unwrap();
+
+    let config = load_config(&dir.path().join("govbot.yml")).unwrap();
+    assert!(config.tags.is_empty(), "researcher fallback should have no preset tags");
+}
@@ -0,0 +1,185 @@
+use govbot::publish::RepoEntry;
+use govbot::repos::sync_all;
+use govbot::source::DataSource;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+/// A [`DataSource`] whose `fetch` behavior is scripted per-call, so tests can
+/// exercise `sync_all`'s timeout/retry/backoff logic without touching the
+/// network or a real git clone.
+struct ScriptedSource {
+    calls: Arc<AtomicUsize>,
+    /// What each call (by index) does: `Ok` succeeds immediately, `Err`
+    /// fails immediately, `Hang` sleeps past whatever timeout the test gives
+    /// `sync_all` so it's abandoned by [`govbot::repos::sync_all`]'s timeout.
+    script: Vec<Behavior>,
+}
+
+enum Behavior {
+    Ok,
+    Err,
+    Hang,
+}
+
+impl DataSource for ScriptedSource {
+    fn fetch(&self, _dest: &Path, _on_progress: &mut dyn FnMut(&str)) -> anyhow::Result<()> {
+        let call = self.calls.fetch_add(1, Ordering::SeqCst);
+        match self.script.get(call).unwrap_or(&Behavior::Err) {
+            Behavior::Ok => Ok(()),
+            Behavior::Err => Err(anyhow::anyhow!("scripted failure on call {}", call)),
+            Behavior::Hang => {
+                thread::sleep(Duration::from_secs(60));
+                Ok(())
+            }
+        }
+    }
+
+    fn entries(&self, _dest: &Path) -> anyhow::Result<Vec<std::path::PathBuf>> {
+        Ok(Vec::new())
+    }
+}
+
+fn entry(name: &str) -> RepoEntry {
+    serde_yaml::from_str(name).unwrap()
+}
+
+#[test]
+fn succeeds_without_retrying_when_the_first_attempt_works() {
+    let dir = tempfile::tempdir().unwrap();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let script_calls = calls.clone();
+
+    let report = sync_all(
+        &[entry("il")],
+        dir.path(),
+        Duration::from_secs(5),
+        3,
+        move |_entry: &RepoEntry| -> Box<dyn DataSource> {
+            Box::new(ScriptedSource {
+                calls: script_calls.clone(),
+                script: vec![Behavior::Ok],
+            })
+        },
+        |_line| {},
+    );
+
+    assert!(report.failures.is_empty());
+    assert!(report.retried.is_empty());
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn retries_on_failure_and_succeeds_within_the_retry_budget() {
+    let dir = tempfile::tempdir().unwrap();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let script_calls = calls.clone();
+
+    let report = sync_all(
+        &[entry("il")],
+        dir.path(),
+        Duration::from_secs(5),
+        3,
+        move |_entry: &RepoEntry| -> Box<dyn DataSource> {
+            Box::new(ScriptedSource {
+                calls: script_calls.clone(),
+                script: vec![Behavior::Err, Behavior::Err, Behavior::Ok],
+            })
+        },
+        |_line| {},
+    );
+
+    assert!(report.failures.is_empty());
+    assert_eq!(report.retried, vec!["il".to_string(), "il".to_string()]);
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn records_a_failure_once_retries_are_exhausted() {
+    let dir = tempfile::tempdir().unwrap();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let script_calls = calls.clone();
+
+    let report = sync_all(
+        &[entry("il")],
+        dir.path(),
+        Duration::from_secs(5),
+        2,
+        move |_entry: &RepoEntry| -> Box<dyn DataSource> {
+            Box::new(ScriptedSource {
+                calls: script_calls.clone(),
+                script: vec![Behavior::Err, Behavior::Err, Behavior::Err],
+            })
+        },
+        |_line| {},
+    );
+
+    assert_eq!(report.failures.len(), 1);
+    assert_eq!(report.failures[0].0, "il");
+    assert_eq!(report.retried, vec!["il".to_string(), "il".to_string()]);
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn a_stalled_fetch_times_out_instead_of_hanging_the_whole_sync() {
+    let dir = tempfile::tempdir().unwrap();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let script_calls = calls.clone();
+
+    let started = std::time::Instant::now();
+    let report = sync_all(
+        &[entry("il")],
+        dir.path(),
+        Duration::from_millis(50),
+        0,
+        move |_entry: &RepoEntry| -> Box<dyn DataSource> {
+            Box::new(ScriptedSource {
+                calls: script_calls.clone(),
+                script: vec![Behavior::Hang],
+            })
+        },
+        |_line| {},
+    );
+
+    // The abandoned thread's 60s sleep must not block sync_all's return.
+    assert!(started.elapsed() < Duration::from_secs(10));
+    assert_eq!(report.failures.len(), 1);
+    assert!(report.failures[0].1.to_string().contains("timed out"));
+}
+
+#[test]
+fn one_failing_entry_does_not_block_the_rest() {
+    let dir = tempfile::tempdir().unwrap();
+    let il_calls = Arc::new(AtomicUsize::new(0));
+    let ca_calls = Arc::new(AtomicUsize::new(0));
+    let il_calls_for_factory = il_calls.clone();
+    let ca_calls_for_factory = ca_calls.clone();
+
+    let report = sync_all(
+        &[entry("il"), entry("ca")],
+        dir.path(),
+        Duration::from_secs(5),
+        0,
+        move |entry: &RepoEntry| -> Box<dyn DataSource> {
+            if entry.name() == "il" {
+                Box::new(ScriptedSource {
+                    calls: il_calls_for_factory.clone(),
+                    script: vec![Behavior::Err],
+                })
+            } else {
+                Box::new(ScriptedSource {
+                    calls: ca_calls_for_factory.clone(),
+                    script: vec![Behavior::Ok],
+                })
+            }
+        },
+        |_line| {},
+    );
+
+    assert_eq!(report.failures.len(), 1);
+    assert_eq!(report.failures[0].0, "il");
+    assert_eq!(il_calls.load(Ordering::SeqCst), 1);
+    assert_eq!(ca_calls.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,36 @@
+use govbot::schema;
+use govbot::wizard::generate_govbot_yml;
+use jsonschema::JSONSchema;
+
+fn assert_validates(yml: &str) {
+    let schema_value = schema::generate();
+    let compiled = JSONSchema::compile(&schema_value).expect("schema should compile");
+
+    let config: serde_json::Value = serde_yaml::from_str(yml).expect("generated yml should parse");
+
+    let result = compiled.validate(&config);
+    if let Err(errors) = result {
+        let messages: Vec<String> = errors.map(|e| e.to_string()).collect();
+        panic!("generated govbot.yml failed schema validation: {:?}", messages);
+    }
+}
+
+#[test]
+fn wizard_generated_config_with_example_tag_validates() {
+    let yml = generate_govbot_yml(&["all".to_string()], true, "https://example.com");
+    assert_validates(&yml);
+}
+
+#[test]
+fn wizard_generated_config_without_example_tag_validates() {
+    let yml = generate_govbot_yml(&["il".to_string(), "ca".to_string()], false, "https://example.com");
+    assert_validates(&yml);
+}
+
+#[test]
+fn schema_requires_absolute_base_url() {
+    let schema_value = schema::generate();
+    let schema_str = serde_json::to_string(&schema_value).unwrap();
+    assert!(schema_str.contains("base_url"));
+    assert!(schema_str.contains("https?"));
+}
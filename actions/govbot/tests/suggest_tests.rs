@@ -0,0 +1,106 @@
+use std::collections::HashSet;
+
+use govbot::suggest::{suggest_tags, TagSuggestion};
+
+fn titles(strs: &[&str]) -> Vec<String> {
+    strs.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn clusters_co_occurring_keywords_into_a_suggestion() {
+    let titles = titles(&[
+        "Increases per-pupil funding for public schools",
+        "Mandates curriculum standards for public schools statewide",
+        "Expands Medicaid eligibility for low-income families",
+        "Increases funding for rural hospital Medicaid reimbursement",
+    ]);
+
+    let suggestions = suggest_tags(&titles, &HashSet::new());
+
+    assert!(
+        suggestions.iter().any(|s| s.include_keywords.contains(&"public".to_string())
+            && s.include_keywords.contains(&"schools".to_string())),
+        "expected a suggestion clustering 'public'/'schools', got {:?}",
+        suggestions
+    );
+}
+
+#[test]
+fn drops_terms_that_appear_in_every_title() {
+    let titles = titles(&[
+        "Legislation expanding broadband access statewide",
+        "Legislation funding rural broadband expansion",
+        "Legislation regulating broadband service pricing",
+    ]);
+
+    // "legislation" and "broadband" both appear in every title, so no
+    // cluster should survive the >50% document-frequency cutoff.
+    let suggestions = suggest_tags(&titles, &HashSet::new());
+    assert!(suggestions.is_empty());
+}
+
+#[test]
+fn skips_clusters_whose_name_is_already_a_configured_tag() {
+    let titles = titles(&[
+        "Increases per-pupil funding for public schools",
+        "Mandates curriculum standards for public schools statewide",
+        "Expands Medicaid eligibility for low-income families",
+        "Increases funding for rural hospital Medicaid reimbursement",
+    ]);
+
+    let mut existing = HashSet::new();
+    existing.insert("funding".to_string());
+
+    let suggestions = suggest_tags(&titles, &existing);
+    assert!(suggestions.iter().all(|s| s.name != "funding"), "got {:?}", suggestions);
+    assert!(suggestions.iter().any(|s| s.name == "public"), "got {:?}", suggestions);
+}
+
+#[test]
+fn yaml_block_includes_description_examples_and_keywords() {
+    let titles = titles(&[
+        "Increases per-pupil funding for public schools",
+        "Mandates curriculum standards for public schools statewide",
+        "Expands Medicaid eligibility for low-income families",
+        "Increases funding for rural hospital Medicaid reimbursement",
+    ]);
+
+    let suggestions = suggest_tags(&titles, &HashSet::new());
+    let suggestion = suggestions.first().expect("expected at least one suggestion");
+    let yaml = suggestion.to_yaml_block();
+
+    assert!(yaml.contains("description: |"));
+    assert!(yaml.contains("examples:"));
+    assert!(yaml.contains("include_keywords:"));
+}
+
+#[test]
+fn to_yaml_block_escapes_embedded_quotes_so_the_result_still_parses() {
+    let suggestion = TagSuggestion {
+        name: "enterprise_zone".to_string(),
+        description: "Legislation establishing enterprise zones.".to_string(),
+        examples: vec![r#"Creates the "South Carolina Enterprise Zone Act""#.to_string()],
+        include_keywords: vec![r#"say "hi""#.to_string()],
+    };
+
+    let yaml = suggestion.to_yaml_block();
+    let indented: String = yaml.lines().map(|l| format!("  {}\n", l)).collect();
+    let full = format!("tags:\n{}", indented);
+    let parsed: serde_yaml::Value =
+        serde_yaml::from_str(&full).expect("to_yaml_block output with quoted fields should still parse as YAML");
+
+    assert_eq!(
+        parsed["tags"]["enterprise_zone"]["examples"][0].as_str().unwrap(),
+        r#"Creates the "South Carolina Enterprise Zone Act""#
+    );
+    assert_eq!(
+        parsed["tags"]["enterprise_zone"]["include_keywords"][0].as_str().unwrap(),
+        r#"say "hi""#
+    );
+}
+
+#[test]
+fn fewer_than_two_titles_yields_no_suggestions() {
+    let suggestions = suggest_tags(&titles(&["Only one bill title here"]), &HashSet::new());
+    assert!(suggestions.is_empty());
+}
@@ -0,0 +1,129 @@
+use govbot::publish::{
+    publish_feed, BuildConfig, GovbotConfig, LegislationItem, TagDefinition, TaggingConfig, TimeoutsConfig,
+};
+use std::collections::HashMap;
+
+fn sample_items() -> Vec<LegislationItem> {
+    vec![
+        LegislationItem {
+            title: "School funding increase".to_string(),
+            summary: "Increases per-pupil funding for public schools statewide.".to_string(),
+            url: "https://example.com/bill/1".to_string(),
+            tags: Vec::new(),
+            ..Default::default()
+        },
+        LegislationItem {
+            title: "Medicaid eligibility expansion".to_string(),
+            summary: "Expands Medicaid eligibility for low-income families.".to_string(),
+            url: "https://example.com/bill/2".to_string(),
+            tags: Vec::new(),
+            ..Default::default()
+        },
+        LegislationItem {
+            title: "Highway maintenance appropriation".to_string(),
+            summary: "Appropriates funds for interstate highway maintenance.".to_string(),
+            url: "https://example.com/bill/3".to_string(),
+            tags: Vec::new(),
+            ..Default::default()
+        },
+    ]
+}
+
+fn base_build() -> BuildConfig {
+    BuildConfig {
+        base_url: "https://example.com".to_string(),
+        output_dir: "docs".to_string(),
+        output_file: "feed.xml".to_string(),
+        timeouts: TimeoutsConfig::default(),
+    }
+}
+
+#[test]
+fn tagged_feed_assigns_matching_tags() {
+    let mut tags = HashMap::new();
+    tags.insert(
+        "education".to_string(),
+        TagDefinition {
+            description: "Legislation related to schools and curriculum standards.".to_string(),
+            examples: vec!["Increases per-pupil funding for public schools".to_string()],
+        },
+    );
+    tags.insert(
+        "health".to_string(),
+        TagDefinition {
+            description: "Legislation related to hospitals and insurance coverage.".to_string(),
+            examples: vec!["Expands Medicaid eligibility for low-income families".to_string()],
+        },
+    );
+
+    let config = GovbotConfig {
+        repos: vec!["all".into()],
+        tags,
+        build: base_build(),
+        tagging: TaggingConfig {
+            threshold: 0.1,
+            ..TaggingConfig::default()
+        },
+        template: None,
+    };
+
+    let dir = tempfile::tempdir().unwrap();
+    let feed_path = dir.path().join("feed.xml");
+    publish_feed(&config, sample_items(), &feed_path).unwrap();
+
+    let feed_xml = std::fs::read_to_string(&feed_path).unwrap();
+    let mut settings = insta::Settings::clone_current();
+    settings.set_snapshot_path("snapshots");
+    settings.bind(|| {
+        insta::assert_snapshot!("tagged_feed", &feed_xml);
+    });
+}
+
+#[test]
+fn published_feed_escapes_xml_special_characters_in_item_fields() {
+    let items = vec![LegislationItem {
+        title: "Fish & Wildlife <Protection> Act".to_string(),
+        summary: "Amends Title 16 <U.S.C. §1> & related provisions".to_string(),
+        url: "https://example.com/bill?a=1&b=2".to_string(),
+        tags: Vec::new(),
+        ..Default::default()
+    }];
+
+    let config = GovbotConfig {
+        repos: vec!["all".into()],
+        tags: HashMap::new(),
+        build: base_build(),
+        tagging: TaggingConfig::default(),
+        template: None,
+    };
+
+    let dir = tempfile::tempdir().unwrap();
+    let feed_path = dir.path().join("feed.xml");
+    publish_feed(&config, items, &feed_path).unwrap();
+
+    let feed_xml = std::fs::read_to_string(&feed_path).unwrap();
+    assert!(feed_xml.contains("<title>Fish &amp; Wildlife &lt;Protection&gt; Act</title>"));
+    assert!(feed_xml.contains(
+        "<description>Amends Title 16 &lt;U.S.C. §1&gt; &amp; related provisions</description>"
+    ));
+    assert!(feed_xml.contains("<link>https://example.com/bill?a=1&amp;b=2</link>"));
+    assert!(!feed_xml.contains("<Protection>"), "unescaped '<' would smuggle in markup");
+}
+
+#[test]
+fn untagged_feed_when_no_tags_configured() {
+    let config = GovbotConfig {
+        repos: vec!["all".into()],
+        tags: HashMap::new(),
+        build: base_build(),
+        tagging: TaggingConfig::default(),
+        template: None,
+    };
+
+    let dir = tempfile::tempdir().unwrap();
+    let feed_path = dir.path().join("feed.xml");
+    publish_feed(&config, sample_items(), &feed_path).unwrap();
+
+    let feed_xml = std::fs::read_to_string(&feed_path).unwrap();
+    assert!(!feed_xml.contains("<category>"));
+}
@@ -0,0 +1,70 @@
+use govbot::publish::{publish_feed, BuildConfig, GovbotConfig, LegislationItem, TaggingConfig, TimeoutsConfig};
+use std::collections::HashMap;
+
+fn config_with_template(template: &str) -> GovbotConfig {
+    GovbotConfig {
+        repos: vec!["all".into()],
+        tags: HashMap::new(),
+        build: BuildConfig {
+            base_url: "https://example.com".to_string(),
+            output_dir: "docs".to_string(),
+            output_file: "feed.xml".to_string(),
+            timeouts: TimeoutsConfig::default(),
+        },
+        tagging: TaggingConfig::default(),
+        template: Some(template.to_string()),
+    }
+}
+
+const CUSTOM_TEMPLATE: &str = "\
+  - {{title}} ({{state}}, {{introduced_date|date:\"%m/%d/%Y\"}})
+{{#if tags}}    tags: {{tags|join:\", \"}}
+{{/if}}";
+
+#[test]
+fn custom_template_with_tags() {
+    let config = config_with_template(CUSTOM_TEMPLATE);
+    let items = vec![LegislationItem {
+        title: "School funding increase".to_string(),
+        summary: "Increases per-pupil funding for public schools statewide.".to_string(),
+        url: "https://example.com/bill/1".to_string(),
+        tags: vec!["education".to_string(), "budget".to_string()],
+        introduced_date: Some("2024-03-05".to_string()),
+        state: Some("il".to_string()),
+    }];
+
+    let dir = tempfile::tempdir().unwrap();
+    let feed_path = dir.path().join("feed.xml");
+    publish_feed(&config, items, &feed_path).unwrap();
+
+    let feed_xml = std::fs::read_to_string(&feed_path).unwrap();
+    let mut settings = insta::Settings::clone_current();
+    settings.set_snapshot_path("snapshots");
+    settings.bind(|| {
+        insta::assert_snapshot!("custom_template_with_tags", &feed_xml);
+    });
+}
+
+#[test]
+fn custom_template_without_tags() {
+    let config = config_with_template(CUSTOM_TEMPLATE);
+    let items = vec![LegislationItem {
+        title: "Highway maintenance appropriation".to_string(),
+        summary: "Appropriates funds for interstate highway maintenance.".to_string(),
+        url: "https://example.com/bill/2".to_string(),
+        tags: Vec::new(),
+        introduced_date: Some("2024-01-15".to_string()),
+        state: Some("ca".to_string()),
+    }];
+
+    let dir = tempfile::tempdir().unwrap();
+    let feed_path = dir.path().join("feed.xml");
+    publish_feed(&config, items, &feed_path).unwrap();
+
+    let feed_xml = std::fs::read_to_string(&feed_path).unwrap();
+    let mut settings = insta::Settings::clone_current();
+    settings.set_snapshot_path("snapshots");
+    settings.bind(|| {
+        insta::assert_snapshot!("custom_template_without_tags", &feed_xml);
+    });
+}
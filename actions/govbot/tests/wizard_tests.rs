@@ -1,3 +1,4 @@
+use govbot::messages;
 use govbot::wizard::{generate_govbot_yml, WizardChoices, WizardSession};
 use govbot::publish::{load_config, get_repos_from_config};
 
@@ -12,6 +13,7 @@ fn wizard_session_all_repos_with_example_tag() {
         repos: vec!["all".to_string()],
         include_example_tag: true,
         base_url: "https://myuser.github.io/my-govbot".to_string(),
+        ..Default::default()
     });
     let mut settings = insta::Settings::clone_current();
     settings.set_snapshot_path("snapshots");
@@ -26,6 +28,7 @@ fn wizard_session_all_repos_own_tags() {
         repos: vec!["all".to_string()],
         include_example_tag: false,
         base_url: "https://example.com".to_string(),
+        ..Default::default()
     });
     let mut settings = insta::Settings::clone_current();
     settings.set_snapshot_path("snapshots");
@@ -40,6 +43,7 @@ fn wizard_session_specific_repos_with_example_tag() {
         repos: vec!["il".to_string(), "ca".to_string(), "ny".to_string()],
         include_example_tag: true,
         base_url: "https://activist.github.io/legislation".to_string(),
+        ..Default::default()
     });
     let mut settings = insta::Settings::clone_current();
     settings.set_snapshot_path("snapshots");
@@ -54,6 +58,7 @@ fn wizard_session_specific_repos_own_tags() {
         repos: vec!["il".to_string(), "ca".to_string(), "ny".to_string()],
         include_example_tag: false,
         base_url: "https://example.com".to_string(),
+        ..Default::default()
     });
     let mut settings = insta::Settings::clone_current();
     settings.set_snapshot_path("snapshots");
@@ -68,6 +73,7 @@ fn wizard_session_single_state() {
         repos: vec!["wy".to_string()],
         include_example_tag: true,
         base_url: "https://sartaj.me/govbot".to_string(),
+        ..Default::default()
     });
     let mut settings = insta::Settings::clone_current();
     settings.set_snapshot_path("snapshots");
@@ -76,6 +82,24 @@ fn wizard_session_single_state() {
     });
 }
 
+#[test]
+fn wizard_session_spanish_locale() {
+    let session = WizardSession::from_choices_with_catalog(
+        &WizardChoices {
+            repos: vec!["il".to_string(), "ca".to_string()],
+            include_example_tag: true,
+            base_url: "https://example.com".to_string(),
+            ..Default::default()
+        },
+        &messages::load("es"),
+    );
+    let mut settings = insta::Settings::clone_current();
+    settings.set_snapshot_path("snapshots");
+    settings.bind(|| {
+        insta::assert_snapshot!("wizard_session_spanish_locale", &session.to_snapshot());
+    });
+}
+
 // ============================================================
 // govbot.yml generation — focused tests on just the YAML output
 // ============================================================
@@ -143,19 +167,14 @@ fn test_generated_yml_is_valid_yaml_with_tag() {
     assert_eq!(repos, vec!["all"]);
 
     // Verify tags exist and have expected structure
-    let tags = config.get("tags").expect("should have tags key");
-    let tags_obj = tags.as_object().expect("tags should be an object");
-    assert!(tags_obj.contains_key("education"), "should contain education tag");
-    let education = tags_obj.get("education").unwrap().as_object().unwrap();
-    assert!(education.contains_key("description"), "education tag should have description");
-    assert!(education.contains_key("examples"), "education tag should have examples");
+    let education = config.tags.get("education").expect("should contain education tag");
+    assert!(!education.description.is_empty(), "education tag should have description");
+    assert!(!education.examples.is_empty(), "education tag should have examples");
 
     // Verify build config
-    let build = config.get("build").expect("should have build key");
-    let build_obj = build.as_object().expect("build should be an object");
-    assert_eq!(build_obj.get("base_url").unwrap().as_str().unwrap(), "https://myuser.github.io/my-govbot");
-    assert_eq!(build_obj.get("output_dir").unwrap().as_str().unwrap(), "docs");
-    assert_eq!(build_obj.get("output_file").unwrap().as_str().unwrap(), "feed.xml");
+    assert_eq!(config.build.base_url, "https://myuser.github.io/my-govbot");
+    assert_eq!(config.build.output_dir, "docs");
+    assert_eq!(config.build.output_file, "feed.xml");
 }
 
 #[test]
@@ -175,15 +194,11 @@ fn test_generated_yml_is_valid_yaml_without_tag() {
     let repos = get_repos_from_config(&config);
     assert_eq!(repos, vec!["il", "ca"]);
 
-    // Verify tags is empty object
-    let tags = config.get("tags").expect("should have tags key");
-    let tags_obj = tags.as_object().expect("tags should be an object");
-    assert!(tags_obj.is_empty(), "tags should be empty when no example tag");
+    // Verify tags is empty
+    assert!(config.tags.is_empty(), "tags should be empty when no example tag");
 
     // Verify build config
-    let build = config.get("build").expect("should have build key");
-    let build_obj = build.as_object().expect("build should be an object");
-    assert_eq!(build_obj.get("base_url").unwrap().as_str().unwrap(), "https://example.com");
+    assert_eq!(config.build.base_url, "https://example.com");
 }
 
 #[test]
@@ -192,6 +207,7 @@ fn test_write_files_creates_govbot_yml() {
         repos: vec!["wy".to_string()],
         include_example_tag: true,
         base_url: "https://sartaj.me/govbot".to_string(),
+        ..Default::default()
     };
     let session = WizardSession::from_choices(&choices);
     let dir = tempfile::tempdir().unwrap();
@@ -215,3 +231,62 @@ fn test_write_files_creates_govbot_yml() {
     let workflow_path = dir.path().join(".github/workflows/build.yml");
     assert!(workflow_path.exists(), "build.yml workflow should exist");
 }
+
+// ============================================================
+// Config validation — precise, user-facing errors for bad configs
+// ============================================================
+
+#[test]
+fn test_load_config_rejects_relative_base_url() {
+    let yml = "repos:\n  - all\ntags: {}\nbuild:\n  base_url: \"example.com\"\n  output_dir: \"docs\"\n  output_file: \"feed.xml\"\n";
+    let dir = tempfile::tempdir().unwrap();
+    let config_path = dir.path().join("govbot.yml");
+    std::fs::write(&config_path, yml).unwrap();
+
+    let err = load_config(&config_path).expect_err("relative base_url should be rejected");
+    assert!(err.to_string().contains("build.base_url must be an absolute URL"));
+}
+
+#[test]
+fn test_load_config_rejects_unknown_repo_type() {
+    let yml = "repos:\n  - name: il\n    type: svn\ntags: {}\nbuild:\n  base_url: \"https://example.com\"\n  output_dir: \"docs\"\n  output_file: \"feed.xml\"\n";
+    let dir = tempfile::tempdir().unwrap();
+    let config_path = dir.path().join("govbot.yml");
+    std::fs::write(&config_path, yml).unwrap();
+
+    let err = load_config(&config_path).expect_err("unknown repos entry type should be rejected");
+    assert!(err.to_string().contains("unknown type 'svn'"));
+}
+
+#[test]
+fn test_load_config_rejects_http_repo_entry_with_no_url() {
+    let yml = "repos:\n  - name: foo\n    type: http\ntags: {}\nbuild:\n  base_url: \"https://example.com\"\n  output_dir: \"docs\"\n  output_file: \"feed.xml\"\n";
+    let dir = tempfile::tempdir().unwrap();
+    let config_path = dir.path().join("govbot.yml");
+    std::fs::write(&config_path, yml).unwrap();
+
+    let err = load_config(&config_path).expect_err("http repos entry with no url should be rejected");
+    assert!(err.to_string().contains("has type 'http' but no url"));
+}
+
+#[test]
+fn test_load_config_rejects_local_repo_entry_with_no_url() {
+    let yml = "repos:\n  - name: foo\n    type: local\ntags: {}\nbuild:\n  base_url: \"https://example.com\"\n  output_dir: \"docs\"\n  output_file: \"feed.xml\"\n";
+    let dir = tempfile::tempdir().unwrap();
+    let config_path = dir.path().join("govbot.yml");
+    std::fs::write(&config_path, yml).unwrap();
+
+    let err = load_config(&config_path).expect_err("local repos entry with no url should be rejected");
+    assert!(err.to_string().contains("has type 'local' but no url"));
+}
+
+#[test]
+fn test_load_config_rejects_tag_missing_description() {
+    let yml = "repos:\n  - all\ntags:\n  education:\n    examples:\n      - \"Example bill\"\nbuild:\n  base_url: \"https://example.com\"\n  output_dir: \"docs\"\n  output_file: \"feed.xml\"\n";
+    let dir = tempfile::tempdir().unwrap();
+    let config_path = dir.path().join("govbot.yml");
+    std::fs::write(&config_path, yml).unwrap();
+
+    let err = load_config(&config_path).expect_err("tag missing description should be rejected");
+    assert!(err.to_string().contains("tag 'education' is missing description"));
+}